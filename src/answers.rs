@@ -0,0 +1,180 @@
+use crate::error::Error;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Default location of the answers file, relative to the working directory.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+
+/// Hash identifying a puzzle input (64 bit FNV-1a).
+///
+/// Line endings and trailing whitespace are ignored, so the same input saved on different
+/// platforms gets the same hash.
+pub fn input_hash(input: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for line in input.trim_end().lines() {
+        for byte in line.trim_end().bytes().chain(Some(b'\n')) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    hash
+}
+
+type Key = (u16, u8, u8, u64);
+
+fn parse_entry(line: &str) -> Result<(Key, String), Error> {
+    let mut fields = line.splitn(5, ' ');
+    let mut field = |name: &str| {
+        fields
+            .next()
+            .filter(|f| !f.is_empty())
+            .ok_or_else(|| Error::parse(line, line, format!("missing {}", name)))
+    };
+    let year = field("year")?;
+    let day = field("day")?;
+    let part = field("part")?;
+    let hash = field("input hash")?;
+    let answer = field("answer")?;
+
+    let key = (
+        year.parse()
+            .map_err(|_| Error::parse(line, year, "invalid year"))?,
+        day.parse()
+            .map_err(|_| Error::parse(line, day, "invalid day"))?,
+        part.parse()
+            .map_err(|_| Error::parse(line, part, "invalid part"))?,
+        u64::from_str_radix(hash, 16)
+            .map_err(|_| Error::parse(line, hash, "invalid input hash"))?,
+    );
+
+    Ok((key, String::from(answer)))
+}
+
+/// Known-correct answers, keyed by year, day, part and input hash.
+///
+/// Stored as a text file with one `<year> <day> <part> <input hash> <answer>` entry per line,
+/// the hash being written in hexadecimal.
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+    answers: BTreeMap<Key, String>,
+}
+
+impl AnswerStore {
+    pub fn new() -> AnswerStore {
+        AnswerStore::default()
+    }
+
+    pub fn parse(contents: &str) -> Result<AnswerStore, Error> {
+        let mut answers = BTreeMap::new();
+
+        for (idx, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, answer) = parse_entry(line).map_err(|e| e.offset_lines(idx))?;
+            answers.insert(key, answer);
+        }
+
+        Ok(AnswerStore { answers })
+    }
+
+    /// Loads the answers from `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> Result<AnswerStore, Error> {
+        match fs::read_to_string(path) {
+            Ok(contents) => AnswerStore::parse(&contents).map_err(|e| {
+                Error::Input(format!("invalid answers file {}: {}", path.display(), e))
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::new()),
+            Err(e) => Err(Error::Input(format!(
+                "could not read answers file {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.to_string()).map_err(|e| {
+            Error::Input(format!(
+                "could not write answers file {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8, input_hash: u64) -> Option<&str> {
+        self.answers
+            .get(&(year, day, part, input_hash))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, input_hash: u64, answer: String) {
+        self.answers.insert((year, day, part, input_hash), answer);
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl std::fmt::Display for AnswerStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((year, day, part, hash), answer) in &self.answers {
+            writeln!(f, "{} {} {} {:016x} {}", year, day, part, hash, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash("1\n2\n"), input_hash("1\r\n2\r\n"));
+        assert_eq!(input_hash("1\n2"), input_hash("1 \n2\n\n"));
+        assert_ne!(input_hash("1\n2"), input_hash("2\n1"));
+        assert_ne!(input_hash("12"), input_hash("1\n2"));
+    }
+
+    #[test]
+    fn test_answer_store_round_trip() {
+        let mut store = AnswerStore::new();
+        store.insert(2020, 21, 2, 0xabc, String::from("mxmxvkd,sqjhc,fvjkl"));
+        store.insert(2020, 1, 1, input_hash("1721"), String::from("514579"));
+        store.insert(2021, 1, 1, input_hash("1721"), String::from("1"));
+
+        let parsed = AnswerStore::parse(&store.to_string()).unwrap();
+
+        assert_eq!(parsed, store);
+        assert_eq!(parsed.get(2020, 21, 2, 0xabc), Some("mxmxvkd,sqjhc,fvjkl"));
+        assert_eq!(parsed.get(2020, 21, 1, 0xabc), None);
+        assert_eq!(parsed.get(2021, 21, 2, 0xabc), None);
+        assert_eq!(parsed.get(2021, 1, 1, input_hash("1721")), Some("1"));
+        assert_eq!(parsed.len(), 3);
+    }
+
+    #[test]
+    fn test_answer_store_parse_errors() {
+        assert!(AnswerStore::parse("# comment\n\n2020 1 2 ff 42\n").is_ok());
+        assert_eq!(
+            AnswerStore::parse("2020 1 2 ff 42\n2020 1 x ff 42").unwrap_err(),
+            Error::parse("2020 1 x ff 42", "x", "invalid part").offset_lines(1)
+        );
+        assert!(AnswerStore::parse("2020 1 2 zz 42").is_err());
+        assert!(AnswerStore::parse("2020 1 2 ff").is_err());
+        // entries from before the year was part of the key
+        assert!(AnswerStore::parse("1 2 ff 42").is_err());
+    }
+}
@@ -0,0 +1,376 @@
+//! Cellular automata: cells are alive or dead, and all of them are updated at once from the
+//! number of live neighbors they have.
+//!
+//! The layout of the cells is given by a [`Topology`], the update by a birth/survival [`Rule`].
+//! Live cells are kept either in a set ([`Automaton::sparse`]), which suits unbounded spaces
+//! where few cells are alive, or in a vector over all the cells of a finite topology
+//! ([`Automaton::dense`]), whose neighborhoods are then only computed once.
+
+use crate::grid::{HexTile, NEIGHBORS8};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Which cells neighbor each other.
+pub trait Topology {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbors(&self, cell: Self::Cell) -> Vec<Self::Cell>;
+}
+
+/// A topology with finitely many cells.
+pub trait FiniteTopology: Topology {
+    fn cells(&self) -> Vec<Self::Cell>;
+}
+
+/// A bounded square grid where each cell has up to 8 neighbors. Cells are `(x, y)` positions as
+/// in [`crate::grid`].
+#[derive(Debug, Clone, Copy)]
+pub struct SquareGrid {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Topology for SquareGrid {
+    type Cell = (usize, usize);
+
+    fn neighbors(&self, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        NEIGHBORS8
+            .iter()
+            .map(|(dx, dy)| (x as isize + dx, y as isize + dy))
+            .filter(|&(x, y)| {
+                x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+            })
+            .map(|(x, y)| (x as usize, y as usize))
+            .collect()
+    }
+}
+
+impl FiniteTopology for SquareGrid {
+    fn cells(&self) -> Vec<(usize, usize)> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .collect()
+    }
+}
+
+/// The unbounded `N`-dimensional integer lattice, where each cell has `3^N - 1` neighbors: all
+/// the cells that differ by at most 1 on every coordinate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lattice<const N: usize>;
+
+impl<const N: usize> Topology for Lattice<N> {
+    type Cell = [i32; N];
+
+    fn neighbors(&self, cell: [i32; N]) -> Vec<[i32; N]> {
+        let mut neighbors = vec![cell];
+        for dimension in 0..N {
+            neighbors = neighbors
+                .into_iter()
+                .flat_map(|c| {
+                    (-1..=1).map(move |delta| {
+                        let mut c = c;
+                        c[dimension] += delta;
+                        c
+                    })
+                })
+                .collect();
+        }
+        neighbors.retain(|&c| c != cell);
+        neighbors
+    }
+}
+
+/// The unbounded hexagonal grid of [`HexTile`]s, where each tile has 6 neighbors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HexGrid;
+
+impl Topology for HexGrid {
+    type Cell = HexTile;
+
+    fn neighbors(&self, tile: HexTile) -> Vec<HexTile> {
+        tile.neighbors().collect()
+    }
+}
+
+/// Which cells are alive in the next generation, depending on how many live neighbors they
+/// have: dead cells come to life with one of the `birth` counts, live cells stay alive with one
+/// of the `survival` counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Rule {
+        Rule {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+
+    /// Conway's Game of Life: B3/S23.
+    pub fn life() -> Rule {
+        Rule::new(&[3], &[2, 3])
+    }
+
+    pub fn is_alive(&self, alive: bool, live_neighbors: usize) -> bool {
+        if alive {
+            self.survival.contains(&live_neighbors)
+        } else {
+            self.birth.contains(&live_neighbors)
+        }
+    }
+}
+
+enum Storage<C> {
+    Sparse(HashSet<C>),
+    Dense {
+        cells: Vec<C>,
+        neighbors: Vec<Vec<usize>>,
+        alive: Vec<bool>,
+    },
+}
+
+pub struct Automaton<T: Topology> {
+    topology: T,
+    rule: Rule,
+    storage: Storage<T::Cell>,
+    generation: usize,
+}
+
+impl<T: Topology> Automaton<T> {
+    /// Automaton keeping only its live cells.
+    ///
+    /// Only the neighbors of live cells are considered for birth, so the rule must not bring
+    /// cells without live neighbors to life.
+    pub fn sparse<I>(topology: T, rule: Rule, alive: I) -> Automaton<T>
+    where
+        I: IntoIterator<Item = T::Cell>,
+    {
+        assert!(
+            !rule.is_alive(false, 0),
+            "a sparse automaton cannot give birth to isolated cells"
+        );
+
+        Automaton {
+            topology,
+            rule,
+            storage: Storage::Sparse(alive.into_iter().collect()),
+            generation: 0,
+        }
+    }
+
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+
+    /// Number of steps taken so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn is_alive(&self, cell: T::Cell) -> bool {
+        match &self.storage {
+            Storage::Sparse(alive) => alive.contains(&cell),
+            Storage::Dense { cells, alive, .. } => cells
+                .iter()
+                .position(|&c| c == cell)
+                .is_some_and(|idx| alive[idx]),
+        }
+    }
+
+    pub fn live_cells(&self) -> Vec<T::Cell> {
+        match &self.storage {
+            Storage::Sparse(alive) => alive.iter().copied().collect(),
+            Storage::Dense { cells, alive, .. } => cells
+                .iter()
+                .zip(alive)
+                .filter(|(_, &alive)| alive)
+                .map(|(&cell, _)| cell)
+                .collect(),
+        }
+    }
+
+    pub fn count_alive(&self) -> usize {
+        match &self.storage {
+            Storage::Sparse(alive) => alive.len(),
+            Storage::Dense { alive, .. } => alive.iter().filter(|&&alive| alive).count(),
+        }
+    }
+
+    /// Computes the next generation. Returns `false` if it is the same as the current one.
+    pub fn step(&mut self) -> bool {
+        let rule = &self.rule;
+        let changed = match &mut self.storage {
+            Storage::Sparse(alive) => {
+                let mut live_neighbors: HashMap<T::Cell, usize> = HashMap::new();
+                for &cell in alive.iter() {
+                    live_neighbors.entry(cell).or_insert(0);
+                    for neighbor in self.topology.neighbors(cell) {
+                        *live_neighbors.entry(neighbor).or_insert(0) += 1;
+                    }
+                }
+
+                let next: HashSet<T::Cell> = live_neighbors
+                    .into_iter()
+                    .filter(|(cell, count)| rule.is_alive(alive.contains(cell), *count))
+                    .map(|(cell, _)| cell)
+                    .collect();
+
+                let changed = next != *alive;
+                *alive = next;
+                changed
+            }
+            Storage::Dense {
+                neighbors, alive, ..
+            } => {
+                let next: Vec<bool> = neighbors
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, cell_neighbors)| {
+                        let count = cell_neighbors.iter().filter(|&&n| alive[n]).count();
+                        rule.is_alive(alive[idx], count)
+                    })
+                    .collect();
+
+                let changed = next != *alive;
+                *alive = next;
+                changed
+            }
+        };
+
+        self.generation += 1;
+        changed
+    }
+
+    /// Takes `generations` steps, calling `on_generation` after each of them.
+    pub fn run<F>(&mut self, generations: usize, mut on_generation: F)
+    where
+        F: FnMut(&Automaton<T>),
+    {
+        for _ in 0..generations {
+            self.step();
+            on_generation(self);
+        }
+    }
+
+    /// Steps until a generation is the same as the previous one, calling `on_generation` after
+    /// each step. Returns the number of steps taken.
+    pub fn run_until_stable<F>(&mut self, mut on_generation: F) -> usize
+    where
+        F: FnMut(&Automaton<T>),
+    {
+        let start = self.generation;
+        loop {
+            let changed = self.step();
+            on_generation(self);
+            if !changed {
+                return self.generation - start;
+            }
+        }
+    }
+}
+
+impl<T: FiniteTopology> Automaton<T> {
+    /// Automaton keeping the state of every cell of its topology. Cells of `alive` which are
+    /// not part of the topology are ignored.
+    pub fn dense<I>(topology: T, rule: Rule, alive: I) -> Automaton<T>
+    where
+        I: IntoIterator<Item = T::Cell>,
+    {
+        let cells = topology.cells();
+        let index: HashMap<T::Cell, usize> =
+            cells.iter().enumerate().map(|(idx, &c)| (c, idx)).collect();
+
+        let neighbors = cells
+            .iter()
+            .map(|&cell| {
+                topology
+                    .neighbors(cell)
+                    .iter()
+                    .filter_map(|n| index.get(n).copied())
+                    .collect()
+            })
+            .collect();
+
+        let mut live = vec![false; cells.len()];
+        for cell in alive {
+            if let Some(&idx) = index.get(&cell) {
+                live[idx] = true;
+            }
+        }
+
+        Automaton {
+            topology,
+            rule,
+            storage: Storage::Dense {
+                cells,
+                neighbors,
+                alive: live,
+            },
+            generation: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinker() {
+        let blinker = [(1, 0), (1, 1), (1, 2)];
+        let topology = SquareGrid {
+            width: 3,
+            height: 3,
+        };
+
+        for mut automaton in [
+            Automaton::sparse(topology, Rule::life(), blinker),
+            Automaton::dense(topology, Rule::life(), blinker),
+        ] {
+            assert!(automaton.step());
+            let mut cells = automaton.live_cells();
+            cells.sort_unstable();
+            assert_eq!(cells, vec![(0, 1), (1, 1), (2, 1)]);
+
+            let mut counts = Vec::new();
+            automaton.run(3, |a| counts.push((a.generation(), a.count_alive())));
+            assert_eq!(counts, vec![(2, 3), (3, 3), (4, 3)]);
+            assert!(automaton.is_alive((1, 0)));
+        }
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        // a block of 4 cells never changes, the lone cell dies in the first step
+        let cells = vec![(0, 0), (1, 0), (0, 1), (1, 1), (4, 4)];
+        let mut automaton = Automaton::dense(
+            SquareGrid {
+                width: 5,
+                height: 5,
+            },
+            Rule::life(),
+            cells,
+        );
+
+        assert_eq!(automaton.run_until_stable(|_| {}), 2);
+        assert_eq!(automaton.count_alive(), 4);
+        assert_eq!(automaton.generation(), 2);
+    }
+
+    #[test]
+    fn test_lattice_neighbors() {
+        assert_eq!(Lattice::<1>.neighbors([0]), vec![[-1], [1]]);
+        assert_eq!(Lattice::<3>.neighbors([0, 0, 0]).len(), 26);
+        assert_eq!(Lattice::<4>.neighbors([1, 2, 3, 4]).len(), 80);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sparse_rejects_birth_without_neighbors() {
+        Automaton::sparse(Lattice::<2>, Rule::new(&[0], &[]), vec![]);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::error::Error;
+use crate::runner;
+use std::time::Duration;
+
+/// Summary of repeated timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => samples[n / 2],
+            _ => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        })
+    }
+}
+
+/// Timings of a day over several runs, with the most heap each step took in any of them.
+#[derive(Debug)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parse_peak_heap: Option<usize>,
+    /// Only the parts the day has, with their timings and peak heap.
+    pub parts: Vec<(u8, Stats, Option<usize>)>,
+}
+
+/// Parses `input` and solves both parts of `day` of `year` `runs` times, timing each step
+/// separately.
+pub fn bench_day(year: u16, day: u8, input: &str, runs: usize) -> Result<DayBench, Error> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut parse_peak_heap = None;
+    let mut parts: Vec<(u8, Vec<Duration>, Option<usize>)> = Vec::new();
+
+    for _ in 0..runs {
+        let run = runner::run_day(year, day, input, &[1, 2])?;
+        parse.push(run.parse_duration);
+        parse_peak_heap = parse_peak_heap.max(run.parse_peak_heap);
+
+        for part_run in run.parts {
+            match part_run.answer {
+                Ok(_) => {}
+                Err(Error::MissingPart { .. }) => continue,
+                Err(e) => return Err(e),
+            }
+
+            match parts.iter_mut().find(|(part, ..)| *part == part_run.part) {
+                Some((_, samples, peak_heap)) => {
+                    samples.push(part_run.duration);
+                    *peak_heap = (*peak_heap).max(part_run.peak_heap);
+                }
+                None => parts.push((part_run.part, vec![part_run.duration], part_run.peak_heap)),
+            }
+        }
+    }
+
+    let parse = Stats::from_samples(&parse)
+        .ok_or_else(|| Error::Input(String::from("a benchmark needs at least one run")))?;
+    let parts = parts
+        .iter()
+        .filter_map(|(part, samples, peak_heap)| {
+            Some((*part, Stats::from_samples(samples)?, *peak_heap))
+        })
+        .collect();
+
+    Ok(DayBench {
+        year,
+        day,
+        runs,
+        parse,
+        parse_peak_heap,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+        assert_eq!(
+            Stats::from_samples(&[ms(3), ms(1), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            })
+        );
+        assert_eq!(
+            Stats::from_samples(&[ms(4), ms(1), ms(10), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(10)
+            })
+        );
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let bench = bench_day(2020, 25, "5764801\n17807724", 3).unwrap();
+
+        assert_eq!((bench.year, bench.day), (2020, 25));
+        assert_eq!(bench.runs, 3);
+        assert_eq!(bench.parts.len(), 1);
+        assert_eq!(bench.parts[0].0, 1);
+        // the allocator of the tests counts the heap
+        assert!(bench.parse_peak_heap >= Some(2 * std::mem::size_of::<u64>()));
+        assert!(bench.parts[0].2.is_some());
+        assert!(bench.parse.min <= bench.parse.median && bench.parse.median <= bench.parse.max);
+
+        assert!(bench_day(2020, 25, "5764801\n17807724", 0).is_err());
+    }
+}
@@ -0,0 +1,314 @@
+//! Linting of puzzle inputs: every problem the parser of a day finds, not only the first one.
+//!
+//! The parsers stop at the first malformed line. [`check`] drops that line and parses the rest
+//! again, until what is left parses or the problem cannot be pinned to a line, so that one run
+//! reports all the problems with the lines they are on. What was parsed in the end is
+//! described by the day's [`Summary`]: how many records of each kind, the ranges of their
+//! values, ...
+
+use crate::error::Error;
+use crate::solution;
+use std::collections::HashSet;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Facts about a parsed input, in the order they were added.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Summary {
+    facts: Vec<(String, String)>,
+}
+
+impl Summary {
+    pub fn new() -> Summary {
+        Summary::default()
+    }
+
+    pub fn fact<T: fmt::Display>(mut self, name: &str, value: T) -> Summary {
+        self.facts.push((String::from(name), value.to_string()));
+        self
+    }
+
+    /// The smallest and the largest of `values`.
+    pub fn range<T, I>(self, name: &str, values: I) -> Summary
+    where
+        T: Ord + Clone + fmt::Display,
+        I: IntoIterator<Item = T>,
+    {
+        let bounds = values.into_iter().fold(None, |bounds, value| match bounds {
+            None => Some((value.clone(), value)),
+            Some((min, max)) => Some((min.min(value.clone()), max.max(value))),
+        });
+        match bounds {
+            Some((min, max)) if min == max => self.fact(name, min),
+            Some((min, max)) => self.fact(name, format!("{} to {}", min, max)),
+            None => self.fact(name, "none"),
+        }
+    }
+
+    pub fn facts(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.facts
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in self.facts() {
+            writeln!(f, "{}: {}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of checking the input of a day.
+#[derive(Debug)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    /// Lines of the input.
+    pub lines: usize,
+    /// Problems found, in the order of the lines they are on.
+    pub problems: Vec<Error>,
+    /// What was parsed once the lines with problems were dropped, `None` if nothing could be.
+    pub summary: Option<Summary>,
+}
+
+/// Blocks of lines separated by blank lines, as records or sections of an input.
+struct Blocks {
+    /// Block of each line, blank lines belonging to the block before them.
+    of_line: Vec<Option<usize>>,
+    /// First line of each block.
+    starts: Vec<usize>,
+}
+
+impl Blocks {
+    fn new(lines: &[&str]) -> Blocks {
+        let mut blocks = Blocks {
+            of_line: Vec::with_capacity(lines.len()),
+            starts: Vec::new(),
+        };
+        for (idx, line) in lines.iter().enumerate() {
+            let blank = line.trim().is_empty();
+            let previous = idx.checked_sub(1).map(|previous| lines[previous]);
+            if !blank && previous.is_none_or(|previous| previous.trim().is_empty()) {
+                blocks.starts.push(idx);
+            }
+            blocks.of_line.push(blocks.starts.len().checked_sub(1));
+        }
+        blocks
+    }
+
+    /// Block of the line at `idx`, past the end of the input for the last block.
+    fn of(&self, idx: usize) -> Option<usize> {
+        match self.of_line.get(idx) {
+            Some(&block) => block,
+            None => self.starts.len().checked_sub(1),
+        }
+    }
+
+    /// Whether the line at `idx` names the block it starts, like `Tile 2311:`.
+    fn is_header(&self, lines: &[&str], idx: usize) -> bool {
+        let block = self.of_line[idx];
+        let next = lines.get(idx + 1).filter(|next| !next.trim().is_empty());
+        block.is_some_and(|block| self.starts[block] == idx)
+            && next.is_some()
+            && lines[idx].trim_end().ends_with(':')
+    }
+}
+
+/// Parses `input` with the parser of `day` of `year`, reporting every problem it finds.
+///
+/// A problem on a line drops that line. A problem with a whole block drops the block: one on
+/// its header, or one found where it ends, such as a tile missing a row. The latter is not
+/// reported if a line of the block was dropped already, since dropping the line caused it.
+pub fn check(year: u16, day: u8, input: &str) -> Result<Report, Error> {
+    let day_solution = solution::find(year, day)?;
+    let lines: Vec<&str> = input.lines().collect();
+    let blocks = Blocks::new(&lines);
+    // indices of the lines parsed in the next round
+    let mut kept: Vec<usize> = (0..lines.len()).collect();
+    // blocks some lines were dropped from
+    let mut damaged = HashSet::new();
+    let mut problems = Vec::new();
+
+    let summary = loop {
+        let text = if kept.len() == lines.len() {
+            String::from(input)
+        } else {
+            kept.iter()
+                .map(|&idx| format!("{}\n", lines[idx]))
+                .collect()
+        };
+
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| day_solution.parse_input(&text)));
+        let error = match parsed {
+            Ok(Ok(parsed)) => break Some(day_solution.summarize(parsed.as_ref())),
+            Ok(Err(error)) => error.with_day(day),
+            Err(_) => {
+                problems.push(Error::Panicked { day, part: None });
+                break None;
+            }
+        };
+        let (line, error) = match error {
+            Error::Parse {
+                day,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                // past the last line for a truncated input
+                let idx = line.checked_sub(1).and_then(|idx| kept.get(idx).copied());
+                let line = match idx {
+                    Some(idx) => idx + 1,
+                    None => lines.len() + line - kept.len(),
+                };
+                let error = Error::Parse {
+                    day,
+                    line,
+                    column,
+                    text,
+                    message,
+                };
+                (idx.unwrap_or(lines.len()), error)
+            }
+            error => {
+                problems.push(error);
+                break None;
+            }
+        };
+
+        let on_line = lines.get(line).is_some_and(|text| !text.trim().is_empty());
+        if on_line && !blocks.is_header(&lines, line) {
+            problems.push(error);
+            damaged.extend(blocks.of(line));
+            kept.retain(|&idx| idx != line);
+            continue;
+        }
+
+        let block = match blocks.of(line) {
+            Some(block) => block,
+            None => {
+                problems.push(error);
+                break None;
+            }
+        };
+        let caused_by_drop = !on_line && damaged.contains(&block);
+        if !caused_by_drop {
+            problems.push(error);
+        }
+        damaged.insert(block);
+        let before = kept.len();
+        kept.retain(|&idx| blocks.of_line[idx] != Some(block));
+        if kept.len() == before {
+            break None;
+        }
+    };
+
+    Ok(Report {
+        year,
+        day,
+        lines: lines.len(),
+        problems,
+        summary,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let summary = Summary::new()
+            .fact("entries", 3)
+            .range("values", vec![4, -2, 9])
+            .range("single", vec![7])
+            .range("empty", Vec::<u8>::new());
+
+        assert_eq!(
+            summary.to_string(),
+            "entries: 3\nvalues: -2 to 9\nsingle: 7\nempty: none\n"
+        );
+    }
+
+    #[test]
+    fn test_check_reports_every_problem() {
+        let report = check(
+            2020,
+            2,
+            "1-3 a: abcde\n1-x b: cdefg\n2-9 c: ccccccccc\nfoo\n",
+        )
+        .unwrap();
+
+        assert_eq!(report.lines, 4);
+        let problems: Vec<String> = report.problems.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "day 2, line 2, column 3: invalid number: 'x'",
+                "day 2, line 4, column 1: missing ':' before the password: 'foo'"
+            ]
+        );
+        let summary = report.summary.unwrap();
+        assert_eq!(
+            summary.facts().next(),
+            Some(("passwords", "2")),
+            "{}",
+            summary
+        );
+    }
+
+    #[test]
+    fn test_check_valid_inputs() {
+        for (day, _) in solution::solutions(2020) {
+            let input = crate::generate::generate(day, 0, 10).unwrap();
+            let report = check(2020, day, &input).unwrap();
+            assert!(report.problems.is_empty(), "day {}", day);
+            assert!(report.summary.unwrap().facts().count() > 0, "day {}", day);
+        }
+        assert_eq!(check(2020, 26, "").unwrap_err(), Error::UnknownDay(26));
+    }
+
+    #[test]
+    fn test_check_drops_broken_blocks() {
+        let tile = |id: u32| {
+            let mut tile = format!("Tile {}:\n", id);
+            for row in 0..10 {
+                tile.push_str(if row % 2 == 0 {
+                    "#.#.#.#.#.\n"
+                } else {
+                    "..##..##..\n"
+                });
+            }
+            tile
+        };
+        let tiles: Vec<String> = (1..=4).map(tile).collect();
+        let mut input = tiles.join("\n");
+        // a bad pixel in the first tile, a bad id for the third one
+        input = input.replacen("#.#.#.#.#.", "#.#.?.#.#.", 1);
+        input = input.replace("Tile 3:", "Tile x:");
+
+        let report = check(2020, 20, &input).unwrap();
+        let problems: Vec<String> = report.problems.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "day 20, line 2, column 5: invalid character: '?'",
+                "day 20, line 25, column 6: invalid number: 'x'"
+            ]
+        );
+        assert_eq!(report.summary.unwrap().facts().next(), Some(("tiles", "2")));
+    }
+
+    #[test]
+    fn test_check_truncated_input() {
+        // the second line of day 13 is missing
+        let report = check(2020, 13, "939\n").unwrap();
+
+        assert_eq!(report.problems.len(), 1);
+        assert!(matches!(report.problems[0], Error::Parse { line: 2, .. }));
+        assert_eq!(report.summary, None);
+    }
+}
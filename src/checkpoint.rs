@@ -0,0 +1,328 @@
+//! Saving the state of long simulations at intervals, so that they can be resumed later.
+//!
+//! A simulation is saved in `<directory>/<name>.ckpt` as a [`Snapshot`]: a header followed by
+//! little-endian 64-bit values. When it is run again towards the same or a later position, it
+//! starts from the last snapshot instead of from scratch.
+
+use crate::error::Error;
+use crate::progress::Progress;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Steps between two snapshots, unless configured otherwise.
+pub const DEFAULT_INTERVAL: usize = 5_000_000;
+
+const MAGIC: &[u8; 8] = b"AOC2020C";
+
+static CONFIGURED: Mutex<Option<Checkpointer>> = Mutex::new(None);
+
+/// Sets where the solvers save their simulations, `None` to stop saving them.
+pub fn configure(checkpointer: Option<Checkpointer>) {
+    *CONFIGURED.lock().unwrap() = checkpointer;
+}
+
+/// The checkpointer set by [`configure`], if any.
+pub fn configured() -> Option<Checkpointer> {
+    CONFIGURED.lock().unwrap().clone()
+}
+
+/// Values of a snapshot, in the order they are written.
+#[derive(Debug, Default)]
+pub struct Encoder {
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes the length of `values`, then the values.
+    pub fn values<I>(&mut self, values: I)
+    where
+        I: ExactSizeIterator<Item = u64>,
+    {
+        self.u64(values.len() as u64);
+        for value in values {
+            self.u64(value);
+        }
+    }
+}
+
+/// Reads back the values of an [`Encoder`]. Every method returns `None` once the data runs out.
+#[derive(Debug)]
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    pub fn u64(&mut self) -> Option<u64> {
+        if self.bytes.len() < 8 {
+            return None;
+        }
+        let (value, rest) = self.bytes.split_at(8);
+        self.bytes = rest;
+
+        let mut buffer = [0; 8];
+        buffer.copy_from_slice(value);
+        Some(u64::from_le_bytes(buffer))
+    }
+
+    pub fn values(&mut self) -> Option<Vec<u64>> {
+        let len = self.u64()? as usize;
+        if len > self.bytes.len() / 8 {
+            return None;
+        }
+        (0..len).map(|_| self.u64()).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+/// State that can be saved and read back.
+pub trait Snapshot: Sized {
+    fn encode(&self, encoder: &mut Encoder);
+
+    /// `None` if the values do not form a valid state.
+    fn decode(decoder: &mut Decoder) -> Option<Self>;
+}
+
+/// A simulation advancing step by step.
+pub trait Simulation: Snapshot {
+    /// Number of steps taken so far.
+    fn position(&self) -> usize;
+
+    fn advance_to(&mut self, position: usize);
+
+    /// Whether `self` was reached by advancing `start`, i.e. both started from the same input.
+    fn continues(&self, start: &Self) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpointer {
+    directory: PathBuf,
+    interval: usize,
+}
+
+impl Checkpointer {
+    /// Saves in `directory` every `interval` steps.
+    pub fn new<P: Into<PathBuf>>(directory: P, interval: usize) -> Checkpointer {
+        Checkpointer {
+            directory: directory.into(),
+            interval: interval.max(1),
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.directory.join(format!("{}.ckpt", name))
+    }
+
+    pub fn save<S: Snapshot>(&self, name: &str, state: &S) -> Result<(), Error> {
+        let path = self.path(name);
+        let error = |e: std::io::Error| {
+            Error::Input(format!(
+                "could not save checkpoint {}: {}",
+                path.display(),
+                e
+            ))
+        };
+
+        let mut encoder = Encoder::default();
+        encoder.bytes.extend_from_slice(MAGIC);
+        state.encode(&mut encoder);
+
+        // write next to the old snapshot first, so that an interrupted save does not lose it
+        fs::create_dir_all(&self.directory).map_err(error)?;
+        let temporary = path.with_extension("ckpt.tmp");
+        fs::write(&temporary, &encoder.bytes).map_err(error)?;
+        fs::rename(&temporary, &path).map_err(error)
+    }
+
+    /// The saved state, or `None` if there is no snapshot with that name.
+    pub fn load<S: Snapshot>(&self, name: &str) -> Result<Option<S>, Error> {
+        let path = self.path(name);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(Error::Input(format!(
+                    "could not read checkpoint {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        let mut decoder = Decoder {
+            bytes: bytes.strip_prefix(&MAGIC[..]).unwrap_or_default(),
+        };
+        match S::decode(&mut decoder) {
+            Some(state) if decoder.is_empty() && bytes.starts_with(MAGIC) => Ok(Some(state)),
+            _ => Err(Error::Input(format!(
+                "invalid checkpoint {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Advances `start` to `position`, saving it under `name` along the way and reporting the
+    /// position after each save to `progress`.
+    ///
+    /// The run resumes from the saved state if it continues `start` and is not past `position`.
+    pub fn run<S: Simulation>(
+        &self,
+        name: &str,
+        start: S,
+        position: usize,
+        progress: &mut dyn Progress,
+    ) -> Result<S, Error> {
+        let mut simulation = match self.load::<S>(name)? {
+            Some(saved) if saved.continues(&start) && saved.position() <= position => saved,
+            _ => start,
+        };
+
+        while simulation.position() < position {
+            let next = position.min(simulation.position().saturating_add(self.interval));
+            simulation.advance_to(next);
+            self.save(name, &simulation)?;
+            progress.update(simulation.position(), Some(position));
+        }
+        progress.finish();
+
+        Ok(simulation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::Silent;
+    use std::env;
+
+    /// Counts up from `start`, one per step.
+    #[derive(Debug, PartialEq)]
+    struct Counter {
+        start: u64,
+        steps: usize,
+    }
+
+    impl Snapshot for Counter {
+        fn encode(&self, encoder: &mut Encoder) {
+            encoder.u64(self.start);
+            encoder.u64(self.steps as u64);
+        }
+
+        fn decode(decoder: &mut Decoder) -> Option<Counter> {
+            Some(Counter {
+                start: decoder.u64()?,
+                steps: decoder.u64()? as usize,
+            })
+        }
+    }
+
+    impl Simulation for Counter {
+        fn position(&self) -> usize {
+            self.steps
+        }
+
+        fn advance_to(&mut self, position: usize) {
+            self.steps = position;
+        }
+
+        fn continues(&self, start: &Counter) -> bool {
+            self.start == start.start
+        }
+    }
+
+    fn checkpointer(test: &str) -> Checkpointer {
+        let directory =
+            env::temp_dir().join(format!("code-advent-2020-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        Checkpointer::new(directory, 10)
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let checkpointer = checkpointer("save");
+
+        assert_eq!(checkpointer.load::<Counter>("c").unwrap(), None);
+        checkpointer
+            .save("c", &Counter { start: 3, steps: 7 })
+            .unwrap();
+        assert_eq!(
+            checkpointer.load::<Counter>("c").unwrap(),
+            Some(Counter { start: 3, steps: 7 })
+        );
+
+        fs::write(checkpointer.path("c"), b"AOC2020C\x01").unwrap();
+        assert!(checkpointer.load::<Counter>("c").is_err());
+
+        fs::remove_dir_all(checkpointer.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_run_resumes() {
+        let checkpointer = checkpointer("run");
+
+        let counter = checkpointer
+            .run("c", Counter { start: 1, steps: 0 }, 25, &mut Silent)
+            .unwrap();
+        assert_eq!(counter.steps, 25);
+
+        // a later run starts from the saved position, a different start from scratch
+        checkpointer
+            .save(
+                "c",
+                &Counter {
+                    start: 1,
+                    steps: 20,
+                },
+            )
+            .unwrap();
+        let counter = checkpointer
+            .run("c", Counter { start: 1, steps: 0 }, 30, &mut Silent)
+            .unwrap();
+        assert_eq!(counter.steps, 30);
+        assert_eq!(
+            checkpointer.load::<Counter>("c").unwrap(),
+            Some(Counter {
+                start: 1,
+                steps: 30
+            })
+        );
+
+        let counter = checkpointer
+            .run("c", Counter { start: 2, steps: 0 }, 5, &mut Silent)
+            .unwrap();
+        assert_eq!(counter, Counter { start: 2, steps: 5 });
+
+        fs::remove_dir_all(checkpointer.directory()).unwrap();
+    }
+
+    #[test]
+    fn test_decoder() {
+        let mut encoder = Encoder::default();
+        encoder.values(vec![4, 5].into_iter());
+        encoder.u64(6);
+
+        let mut decoder = Decoder {
+            bytes: &encoder.bytes,
+        };
+        assert_eq!(decoder.values(), Some(vec![4, 5]));
+        assert_eq!(decoder.u64(), Some(6));
+        assert_eq!(decoder.u64(), None);
+        assert!(decoder.is_empty());
+    }
+}
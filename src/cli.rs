@@ -0,0 +1,1167 @@
+use code_advent_2020::checkpoint::{self, Checkpointer};
+use code_advent_2020::differential;
+use code_advent_2020::export;
+use code_advent_2020::input::InputSource;
+use code_advent_2020::viewer;
+use code_advent_2020::watch;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+pub const PARTS: RangeInclusive<u8> = 1..=2;
+
+/// The puzzles started in 2015.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Number of runs of each day when benchmarking, unless `--runs` says otherwise.
+pub const DEFAULT_BENCH_RUNS: usize = 5;
+
+/// Directory exported frames are written to, unless `--out` says otherwise.
+pub const DEFAULT_EXPORT_DIR: &str = "frames";
+
+pub const USAGE: &str = "usage:
+    code-advent-2020 [-v|-vv] run <day> [part] [--year <y>] [--input <path>|-] [--inputs <dir>]
+                              [--format text|json] [--checkpoints <dir> [--checkpoint-every <n>]]
+                              [--progress]
+    code-advent-2020 [-v|-vv] run --all [--year <y>] [--jobs <n>] [--inputs <dir>] [--format text|json]
+                              [--checkpoints <dir> [--checkpoint-every <n>]] [--progress]
+    code-advent-2020 [-v|-vv] verify [--year <y>] [--inputs <dir>] [--answers <path>] [--record]
+                              [--format text|json]
+    code-advent-2020 [-v|-vv] bench <day>|--all [--year <y>] [--runs <n>] [--format table|tsv]
+                              [--inputs <dir>]
+    code-advent-2020 fetch <day>|--all [--year <y>] [--force]
+    code-advent-2020 check <day> [--year <y>] [--input <path>|-] [--inputs <dir>]
+    code-advent-2020 watch <day> [--year <y>] [--input <path>] [--inputs <dir>] [--interval <ms>]
+    code-advent-2020 generate <day> [--seed <n>] [--size <n>]
+    code-advent-2020 differential [day] [--seeds <n>]
+    code-advent-2020 export <day> [part] [--input <path>|-] [--inputs <dir>] [--format ppm|svg]
+                            [--scale <n>] [--out <dir>]
+    code-advent-2020 view <day> [part] [--input <path>|-] [--inputs <dir>] [--delay <ms>]
+    code-advent-2020 help
+
+generate, differential, export and view are about the days of 2020.
+
+options:
+    -v, --verbose     print diagnostics of the solvers to stderr, -vv for even more of them
+    --year <y>        year of the puzzles (default: 2020 for a single day, every year otherwise)
+    --input <path>    read the puzzle input from <path>, or from stdin if <path> is -
+    --inputs <dir>    look for inputs in <dir>/yYYYY/chNN/input.txt (default: $AOC_INPUTS, else
+                      the inputs downloaded by fetch, then src)
+    --force           download inputs again even if they were downloaded already
+    --answers <path>  file of known answers (default: answers.txt)
+    --record          save the answers that are not known yet
+    --checkpoints <dir>
+                      save long simulations in <dir> and resume them from there
+    --checkpoint-every <n>
+                      steps between two saves of a simulation (default: 5000000)
+    --progress        draw the progress of long simulations on stderr
+    --jobs <n>        solve <n> days at a time, then print a table of all the answers with the
+                      time and peak heap of each part
+    --runs <n>        number of times each day is run when benchmarking (default: 5)
+    --seed <n>        seed of a generated input, the same seed gives the same input (default: 0)
+    --size <n>        size of a generated input (default: about the size of the puzzle input)
+    --seeds <n>       number of generated inputs both solvers of a day are compared on
+                      (default: 100)
+    --scale <n>       pixels on each side of an exported cell (default: 4)
+    --out <dir>       directory the exported frames are written to (default: frames)
+    --delay <ms>      time each frame is shown by the viewer (default: 100)
+    --interval <ms>   time between two looks at the watched input (default: 500)
+    --format <fmt>    print results as text or as one JSON object per line, benchmarks as an
+                      aligned table or as tab separated values, export frames as PPM or SVG
+
+environment:
+    AOC_SESSION       session cookie of the puzzle site, inputs are downloaded when it is set
+    AOC_ENDPOINT      site the inputs are downloaded from (default: https://adventofcode.com)
+    AOC_CACHE         directory of the downloaded inputs
+                      (default: $XDG_CACHE_HOME/code-advent-2020 or ~/.cache/code-advent-2020)";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BenchFormat {
+    Table,
+    Tsv,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Help,
+    Run {
+        year: Option<u16>,
+        day: u8,
+        part: Option<u8>,
+        input: Option<InputSource>,
+        inputs_dir: Option<PathBuf>,
+        format: OutputFormat,
+        checkpoints: Option<Checkpointer>,
+        progress: bool,
+    },
+    /// Runs every day, `jobs` of them at a time if set, one after the other otherwise.
+    RunAll {
+        year: Option<u16>,
+        inputs_dir: Option<PathBuf>,
+        format: OutputFormat,
+        checkpoints: Option<Checkpointer>,
+        progress: bool,
+        jobs: Option<usize>,
+    },
+    Verify {
+        year: Option<u16>,
+        inputs_dir: Option<PathBuf>,
+        answers: Option<PathBuf>,
+        record: bool,
+        format: OutputFormat,
+    },
+    /// Benchmarks a single day, or all of them if `day` is `None`.
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        runs: usize,
+        format: BenchFormat,
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Downloads the input of a day, or of all days if `day` is `None`, into the cache.
+    Fetch {
+        year: Option<u16>,
+        day: Option<u8>,
+        force: bool,
+    },
+    /// Reruns a day on its examples and its input whenever the input changes.
+    Watch {
+        year: Option<u16>,
+        day: u8,
+        input: Option<PathBuf>,
+        inputs_dir: Option<PathBuf>,
+        interval: Duration,
+    },
+    /// Reports every problem in the input of a day and what it is made of.
+    Check {
+        year: Option<u16>,
+        day: u8,
+        input: Option<InputSource>,
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Prints a random input, of the default size of the day if `size` is `None`.
+    Generate {
+        day: u8,
+        seed: u64,
+        size: Option<usize>,
+    },
+    /// Compares the two solvers of a day, or of all days with two if `day` is `None`.
+    Differential {
+        day: Option<u8>,
+        seeds: u64,
+    },
+    Export {
+        day: u8,
+        part: u8,
+        input: Option<InputSource>,
+        inputs_dir: Option<PathBuf>,
+        format: export::Format,
+        scale: usize,
+        out: PathBuf,
+    },
+    /// Plays the frames of a day in the terminal.
+    View {
+        day: u8,
+        part: u8,
+        input: Option<InputSource>,
+        inputs_dir: Option<PathBuf>,
+        delay: Duration,
+    },
+}
+
+fn parse_number(value: &str, name: &str, range: RangeInclusive<u8>) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(format!(
+            "unknown {} '{}', expected a number between {} and {}",
+            name,
+            value,
+            range.start(),
+            range.end()
+        )),
+    }
+}
+
+fn parse_year(value: Option<String>) -> Result<u16, String> {
+    let value = value.ok_or("--year requires a year")?;
+    match value.parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(format!("unknown year '{}'", value)),
+    }
+}
+
+fn parse_output_format(value: Option<String>) -> Result<OutputFormat, String> {
+    match value.as_deref() {
+        Some("text") => Ok(OutputFormat::Text),
+        Some("json") => Ok(OutputFormat::Json),
+        _ => Err(String::from("--format requires 'text' or 'json'")),
+    }
+}
+
+fn parse_run<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut all = false;
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut year = None;
+    let mut format = OutputFormat::Text;
+    let mut checkpoints_dir = None;
+    let mut checkpoint_every = None;
+    let mut progress = false;
+    let mut jobs = None;
+    let mut positional = Vec::new();
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                input = Some(InputSource::from_arg(&path));
+            }
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--format" => format = parse_output_format(args.next())?,
+            "--progress" => progress = true,
+            "--jobs" => {
+                let value = args.next().ok_or("--jobs requires a number")?;
+                jobs = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("invalid number of jobs '{}'", value)),
+                };
+            }
+            "--checkpoints" => {
+                let dir = args.next().ok_or("--checkpoints requires a directory")?;
+                checkpoints_dir = Some(PathBuf::from(dir));
+            }
+            "--checkpoint-every" => {
+                let value = args.next().ok_or("--checkpoint-every requires a number")?;
+                checkpoint_every = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("invalid number of steps '{}'", value)),
+                };
+            }
+            "--year" => year = Some(parse_year(args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let checkpoints = match (checkpoints_dir, checkpoint_every) {
+        (Some(dir), every) => Some(Checkpointer::new(
+            dir,
+            every.unwrap_or(checkpoint::DEFAULT_INTERVAL),
+        )),
+        (None, Some(_)) => return Err(String::from("--checkpoint-every requires --checkpoints")),
+        (None, None) => None,
+    };
+
+    if all {
+        if !positional.is_empty() || input.is_some() {
+            return Err(String::from(
+                "--all cannot be combined with a day, part or input",
+            ));
+        }
+        // the bars of days run at the same time would be drawn over each other
+        if progress && jobs.is_some() {
+            return Err(String::from("--progress cannot be combined with --jobs"));
+        }
+        return Ok(Command::RunAll {
+            year,
+            inputs_dir,
+            format,
+            checkpoints,
+            progress,
+            jobs,
+        });
+    }
+    if jobs.is_some() {
+        return Err(String::from("--jobs requires --all"));
+    }
+
+    let mut positional = positional.iter();
+    let day = match positional.next() {
+        Some(day) => parse_number(day, "day", DAYS)?,
+        None => return Err(String::from("missing day")),
+    };
+    let part = match positional.next() {
+        Some(part) => Some(parse_number(part, "part", PARTS)?),
+        None => None,
+    };
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument '{}'", extra));
+    }
+
+    Ok(Command::Run {
+        year,
+        day,
+        part,
+        input,
+        inputs_dir,
+        format,
+        checkpoints,
+        progress,
+    })
+}
+
+fn parse_verify<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut inputs_dir = None;
+    let mut year = None;
+    let mut answers = None;
+    let mut record = false;
+    let mut format = OutputFormat::Text;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--answers" => {
+                let path = args.next().ok_or("--answers requires a path")?;
+                answers = Some(PathBuf::from(path));
+            }
+            "--record" => record = true,
+            "--format" => format = parse_output_format(args.next())?,
+            "--year" => year = Some(parse_year(args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Command::Verify {
+        year,
+        inputs_dir,
+        answers,
+        record,
+        format,
+    })
+}
+
+fn parse_bench<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut all = false;
+    let mut day = None;
+    let mut runs = DEFAULT_BENCH_RUNS;
+    let mut format = BenchFormat::Table;
+    let mut inputs_dir = None;
+    let mut year = None;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--runs" => {
+                let value = args.next().ok_or("--runs requires a number")?;
+                runs = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid number of runs '{}'", value)),
+                };
+            }
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("table") => BenchFormat::Table,
+                    Some("tsv") => BenchFormat::Tsv,
+                    _ => return Err(String::from("--format requires 'table' or 'tsv'")),
+                };
+            }
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--year" => year = Some(parse_year(args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if day.is_none() => day = Some(parse_number(&arg, "day", DAYS)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    match (all, day) {
+        (true, Some(_)) => Err(String::from("--all cannot be combined with a day")),
+        (false, None) => Err(String::from("missing day")),
+        _ => Ok(Command::Bench {
+            year,
+            day,
+            runs,
+            format,
+            inputs_dir,
+        }),
+    }
+}
+
+fn parse_generate<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed requires a number")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed '{}'", value))?;
+            }
+            "--size" => {
+                let value = args.next().ok_or("--size requires a number")?;
+                size = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid size '{}'", value))?,
+                );
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if day.is_none() => day = Some(parse_number(&arg, "day", DAYS)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    match day {
+        Some(day) => Ok(Command::Generate { day, seed, size }),
+        None => Err(String::from("missing day")),
+    }
+}
+
+fn parse_differential<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut seeds = differential::DEFAULT_SEEDS;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seeds" => {
+                let value = args.next().ok_or("--seeds requires a number")?;
+                seeds = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid number of seeds '{}'", value)),
+                };
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if day.is_none() => day = Some(parse_number(&arg, "day", DAYS)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Command::Differential { day, seeds })
+}
+
+fn parse_fetch<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut all = false;
+    let mut day = None;
+    let mut force = false;
+    let mut year = None;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--force" => force = true,
+            "--year" => year = Some(parse_year(args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if day.is_none() => day = Some(parse_number(&arg, "day", DAYS)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    match (all, day) {
+        (true, Some(_)) => Err(String::from("--all cannot be combined with a day")),
+        (false, None) => Err(String::from("missing day")),
+        _ => Ok(Command::Fetch { year, day, force }),
+    }
+}
+
+fn parse_check<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut year = None;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                input = Some(InputSource::from_arg(&path));
+            }
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--year" => year = Some(parse_year(args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if day.is_none() => day = Some(parse_number(&arg, "day", DAYS)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Command::Check {
+        year,
+        day: day.ok_or("missing day")?,
+        input,
+        inputs_dir,
+    })
+}
+
+fn parse_watch<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut year = None;
+    let mut day = None;
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut interval = watch::DEFAULT_INTERVAL;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                // stdin is read once, it cannot change afterwards
+                if path == "-" {
+                    return Err(String::from("watch needs an input file, not stdin"));
+                }
+                input = Some(PathBuf::from(path));
+            }
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--interval" => {
+                let value = args.next().ok_or("--interval requires a number")?;
+                interval = match value.parse() {
+                    Ok(ms) if ms > 0 => Duration::from_millis(ms),
+                    _ => return Err(format!("invalid interval '{}'", value)),
+                };
+            }
+            "--year" => year = Some(parse_year(args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ if day.is_none() => day = Some(parse_number(&arg, "day", DAYS)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Command::Watch {
+        year,
+        day: day.ok_or("missing day")?,
+        input,
+        inputs_dir,
+        interval,
+    })
+}
+
+fn parse_export<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut format = export::Format::Ppm;
+    let mut scale = export::DEFAULT_SCALE;
+    let mut out = PathBuf::from(DEFAULT_EXPORT_DIR);
+    let mut positional = Vec::new();
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                input = Some(InputSource::from_arg(&path));
+            }
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("ppm") => export::Format::Ppm,
+                    Some("svg") => export::Format::Svg,
+                    _ => return Err(String::from("--format requires 'ppm' or 'svg'")),
+                };
+            }
+            "--scale" => {
+                let value = args.next().ok_or("--scale requires a number")?;
+                scale = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid scale '{}'", value)),
+                };
+            }
+            "--out" => {
+                let dir = args.next().ok_or("--out requires a directory")?;
+                out = PathBuf::from(dir);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.iter();
+    let day = match positional.next() {
+        Some(day) => parse_number(day, "day", DAYS)?,
+        None => return Err(String::from("missing day")),
+    };
+    let part = match positional.next() {
+        Some(part) => parse_number(part, "part", PARTS)?,
+        None => 1,
+    };
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument '{}'", extra));
+    }
+
+    Ok(Command::Export {
+        day,
+        part,
+        input,
+        inputs_dir,
+        format,
+        scale,
+        out,
+    })
+}
+
+fn parse_view<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut delay = viewer::DEFAULT_DELAY;
+    let mut positional = Vec::new();
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                input = Some(InputSource::from_arg(&path));
+            }
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs_dir = Some(PathBuf::from(dir));
+            }
+            "--delay" => {
+                let value = args.next().ok_or("--delay requires a number")?;
+                delay = match value.parse() {
+                    Ok(ms) if ms > 0 => Duration::from_millis(ms),
+                    _ => return Err(format!("invalid delay '{}'", value)),
+                };
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.iter();
+    let day = match positional.next() {
+        Some(day) => parse_number(day, "day", DAYS)?,
+        None => return Err(String::from("missing day")),
+    };
+    let part = match positional.next() {
+        Some(part) => parse_number(part, "part", PARTS)?,
+        None => 1,
+    };
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument '{}'", extra));
+    }
+
+    Ok(Command::View {
+        day,
+        part,
+        input,
+        inputs_dir,
+        delay,
+    })
+}
+
+/// Removes the verbosity flags from `args`, wherever they are, and returns how many were given.
+pub fn split_verbosity(args: Vec<String>) -> (u8, Vec<String>) {
+    let mut verbosity = 0u8;
+    let mut rest = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity = verbosity.saturating_add(1),
+            "-vv" => verbosity = verbosity.saturating_add(2),
+            _ => rest.push(arg),
+        }
+    }
+
+    (verbosity, rest)
+}
+
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut args = args;
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("verify") => parse_verify(args),
+        Some("bench") => parse_bench(args),
+        Some("fetch") => parse_fetch(args),
+        Some("check") => parse_check(args),
+        Some("watch") => parse_watch(args),
+        Some("generate") => parse_generate(args),
+        Some("differential") => parse_differential(args),
+        Some("export") => parse_export(args),
+        Some("view") => parse_view(args),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command '{}'", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(s: &str) -> Result<Command, String> {
+        parse(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse_str("run 5"),
+            Ok(Command::Run {
+                year: None,
+                day: 5,
+                part: None,
+                input: None,
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: None,
+                progress: false
+            })
+        );
+        assert_eq!(
+            parse_str("run 12 2 --input foo.txt"),
+            Ok(Command::Run {
+                year: None,
+                day: 12,
+                part: Some(2),
+                input: Some(InputSource::File(PathBuf::from("foo.txt"))),
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: None,
+                progress: false
+            })
+        );
+        assert_eq!(
+            parse_str("run 7 --input - --inputs data --format json"),
+            Ok(Command::Run {
+                year: None,
+                day: 7,
+                part: None,
+                input: Some(InputSource::Stdin),
+                inputs_dir: Some(PathBuf::from("data")),
+                format: OutputFormat::Json,
+                checkpoints: None,
+                progress: false
+            })
+        );
+        assert_eq!(
+            parse_str("run --all"),
+            Ok(Command::RunAll {
+                year: None,
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: None,
+                progress: false,
+                jobs: None
+            })
+        );
+        assert_eq!(
+            parse_str("run --all --inputs data"),
+            Ok(Command::RunAll {
+                year: None,
+                inputs_dir: Some(PathBuf::from("data")),
+                format: OutputFormat::Text,
+                checkpoints: None,
+                progress: false,
+                jobs: None
+            })
+        );
+        assert_eq!(
+            parse_str("run 15 2 --checkpoints ckpt --checkpoint-every 1000"),
+            Ok(Command::Run {
+                year: None,
+                day: 15,
+                part: Some(2),
+                input: None,
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: Some(Checkpointer::new("ckpt", 1000)),
+                progress: false
+            })
+        );
+        assert_eq!(
+            parse_str("run --all --jobs 4"),
+            Ok(Command::RunAll {
+                year: None,
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: None,
+                progress: false,
+                jobs: Some(4)
+            })
+        );
+        assert_eq!(
+            parse_str("run --all --checkpoints ckpt"),
+            Ok(Command::RunAll {
+                year: None,
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: Some(Checkpointer::new("ckpt", checkpoint::DEFAULT_INTERVAL)),
+                progress: false,
+                jobs: None
+            })
+        );
+        assert_eq!(
+            parse_str("run 24 --progress"),
+            Ok(Command::Run {
+                year: None,
+                day: 24,
+                part: None,
+                input: None,
+                inputs_dir: None,
+                format: OutputFormat::Text,
+                checkpoints: None,
+                progress: true
+            })
+        );
+        assert_eq!(parse_str(""), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse_str("verify"),
+            Ok(Command::Verify {
+                year: None,
+                inputs_dir: None,
+                answers: None,
+                record: false,
+                format: OutputFormat::Text
+            })
+        );
+        assert_eq!(
+            parse_str("verify --record --answers a.txt --inputs data --format json"),
+            Ok(Command::Verify {
+                year: None,
+                inputs_dir: Some(PathBuf::from("data")),
+                answers: Some(PathBuf::from("a.txt")),
+                record: true,
+                format: OutputFormat::Json
+            })
+        );
+        assert!(parse_str("verify 5").is_err());
+        assert!(parse_str("verify --answers").is_err());
+        assert!(parse_str("verify --all").is_err());
+        assert!(parse_str("verify --format tsv").is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        assert_eq!(
+            parse_str("watch 9"),
+            Ok(Command::Watch {
+                year: None,
+                day: 9,
+                input: None,
+                inputs_dir: None,
+                interval: watch::DEFAULT_INTERVAL
+            })
+        );
+        assert_eq!(
+            parse_str("watch --input mine.txt 9 --interval 50"),
+            Ok(Command::Watch {
+                year: None,
+                day: 9,
+                input: Some(PathBuf::from("mine.txt")),
+                inputs_dir: None,
+                interval: Duration::from_millis(50)
+            })
+        );
+        assert!(parse_str("watch").is_err());
+        assert!(parse_str("watch 9 --input -").is_err());
+        assert!(parse_str("watch 9 --interval 0").is_err());
+        assert!(parse_str("watch --all").is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(
+            parse_str("check 7 --year 2021"),
+            Ok(Command::Check {
+                year: Some(2021),
+                day: 7,
+                input: None,
+                inputs_dir: None
+            })
+        );
+        assert!(matches!(
+            parse_str("run --all --year 2020"),
+            Ok(Command::RunAll {
+                year: Some(2020),
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_str("fetch --year 2015 --all"),
+            Ok(Command::Fetch {
+                year: Some(2015),
+                day: None,
+                ..
+            })
+        ));
+        assert!(parse_str("verify --year 2014").is_err());
+        assert!(parse_str("bench 1 --year twenty").is_err());
+        assert!(parse_str("run 1 --year").is_err());
+        assert!(parse_str("generate 1 --year 2020").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse_str("bench 15"),
+            Ok(Command::Bench {
+                year: None,
+                day: Some(15),
+                runs: DEFAULT_BENCH_RUNS,
+                format: BenchFormat::Table,
+                inputs_dir: None
+            })
+        );
+        assert_eq!(
+            parse_str("bench --all --runs 3 --format tsv --inputs data"),
+            Ok(Command::Bench {
+                year: None,
+                day: None,
+                runs: 3,
+                format: BenchFormat::Tsv,
+                inputs_dir: Some(PathBuf::from("data"))
+            })
+        );
+        assert!(parse_str("bench").is_err());
+        assert!(parse_str("bench 3 --all").is_err());
+        assert!(parse_str("bench 3 --runs 0").is_err());
+        assert!(parse_str("bench 3 --format xml").is_err());
+        assert!(parse_str("bench 3 4").is_err());
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        assert_eq!(
+            parse_str("generate 7"),
+            Ok(Command::Generate {
+                day: 7,
+                seed: 0,
+                size: None
+            })
+        );
+        assert_eq!(
+            parse_str("generate --size 30 20 --seed 42"),
+            Ok(Command::Generate {
+                day: 20,
+                seed: 42,
+                size: Some(30)
+            })
+        );
+        assert!(parse_str("generate").is_err());
+        assert!(parse_str("generate 26").is_err());
+        assert!(parse_str("generate 5 6").is_err());
+        assert!(parse_str("generate 5 --seed -1").is_err());
+        assert!(parse_str("generate 5 --size").is_err());
+    }
+
+    #[test]
+    fn test_parse_differential() {
+        assert_eq!(
+            parse_str("differential"),
+            Ok(Command::Differential {
+                day: None,
+                seeds: differential::DEFAULT_SEEDS
+            })
+        );
+        assert_eq!(
+            parse_str("differential 23 --seeds 1000"),
+            Ok(Command::Differential {
+                day: Some(23),
+                seeds: 1000
+            })
+        );
+        assert!(parse_str("differential 23 --seeds 0").is_err());
+        assert!(parse_str("differential 13 19").is_err());
+        assert!(parse_str("differential --all").is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(
+            parse_str("fetch 4"),
+            Ok(Command::Fetch {
+                year: None,
+                day: Some(4),
+                force: false
+            })
+        );
+        assert_eq!(
+            parse_str("fetch --force --all"),
+            Ok(Command::Fetch {
+                year: None,
+                day: None,
+                force: true
+            })
+        );
+        assert!(parse_str("fetch").is_err());
+        assert!(parse_str("fetch 4 --all").is_err());
+        assert!(parse_str("fetch 26").is_err());
+    }
+
+    #[test]
+    fn test_parse_check() {
+        assert_eq!(
+            parse_str("check 2"),
+            Ok(Command::Check {
+                year: None,
+                day: 2,
+                input: None,
+                inputs_dir: None
+            })
+        );
+        assert_eq!(
+            parse_str("check --input - 14"),
+            Ok(Command::Check {
+                year: None,
+                day: 14,
+                input: Some(InputSource::Stdin),
+                inputs_dir: None
+            })
+        );
+        assert!(parse_str("check").is_err());
+        assert!(parse_str("check 2 3").is_err());
+        assert!(parse_str("check 2 --all").is_err());
+    }
+
+    #[test]
+    fn test_parse_export() {
+        assert_eq!(
+            parse_str("export 11"),
+            Ok(Command::Export {
+                day: 11,
+                part: 1,
+                input: None,
+                inputs_dir: None,
+                format: export::Format::Ppm,
+                scale: export::DEFAULT_SCALE,
+                out: PathBuf::from(DEFAULT_EXPORT_DIR)
+            })
+        );
+        assert_eq!(
+            parse_str("export 24 2 --input - --format svg --scale 1 --out pictures"),
+            Ok(Command::Export {
+                day: 24,
+                part: 2,
+                input: Some(InputSource::Stdin),
+                inputs_dir: None,
+                format: export::Format::Svg,
+                scale: 1,
+                out: PathBuf::from("pictures")
+            })
+        );
+        assert!(parse_str("export").is_err());
+        assert!(parse_str("export 11 3").is_err());
+        assert!(parse_str("export 11 --format png").is_err());
+        assert!(parse_str("export 11 --scale 0").is_err());
+        assert!(parse_str("export 11 --out").is_err());
+    }
+
+    #[test]
+    fn test_parse_view() {
+        assert_eq!(
+            parse_str("view 12 2 --delay 250"),
+            Ok(Command::View {
+                day: 12,
+                part: 2,
+                input: None,
+                inputs_dir: None,
+                delay: Duration::from_millis(250)
+            })
+        );
+        assert_eq!(
+            parse_str("view 17 --input - --inputs inputs"),
+            Ok(Command::View {
+                day: 17,
+                part: 1,
+                input: Some(InputSource::Stdin),
+                inputs_dir: Some(PathBuf::from("inputs")),
+                delay: viewer::DEFAULT_DELAY
+            })
+        );
+        assert!(parse_str("view").is_err());
+        assert!(parse_str("view 11 --delay 0").is_err());
+        assert!(parse_str("view 11 --delay soon").is_err());
+    }
+
+    #[test]
+    fn test_split_verbosity() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        assert_eq!(split_verbosity(args("run 5")), (0, args("run 5")));
+        assert_eq!(split_verbosity(args("-v run 5 -v")), (2, args("run 5")));
+        assert_eq!(
+            split_verbosity(args("-vv verify --verbose")),
+            (3, args("verify"))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_invalid() {
+        assert!(parse_str("run").is_err());
+        assert!(parse_str("run 0").is_err());
+        assert!(parse_str("run 26").is_err());
+        assert!(parse_str("run 5 3").is_err());
+        assert!(parse_str("run 5 1 1").is_err());
+        assert!(parse_str("run 5 --input").is_err());
+        assert!(parse_str("run 5 --inputs").is_err());
+        assert!(parse_str("run --all 5").is_err());
+        assert!(parse_str("run --all --jobs 0").is_err());
+        assert!(parse_str("run --all --jobs 4 --progress").is_err());
+        assert!(parse_str("run 5 --jobs 2").is_err());
+        assert!(parse_str("run 5 --quiet").is_err());
+        assert!(parse_str("run 5 --format xml").is_err());
+        assert!(parse_str("run 15 --checkpoint-every 10").is_err());
+        assert!(parse_str("run 15 --checkpoints ckpt --checkpoint-every 0").is_err());
+        assert!(parse_str("walk 5").is_err());
+    }
+}
@@ -0,0 +1,202 @@
+//! Differential testing of the days solved twice: once in a straightforward way, once in the
+//! faster way their `Day` uses.
+//!
+//! Both solvers of a [`Pair`] run on the same inputs from [`crate::generate`], and any input on
+//! which their results differ is reported as a [`Disagreement`], along with the seed and size
+//! that generate it again.
+
+use crate::generate;
+use crate::solution::Solution;
+use crate::y2020::{ch13, ch19, ch23};
+use std::fmt;
+use std::ops::Range;
+
+/// Number of inputs each pair is compared on, unless told otherwise.
+pub const DEFAULT_SEEDS: u64 = 100;
+
+/// Two solvers of the same problem.
+#[derive(Debug)]
+pub struct Pair {
+    pub day: u8,
+    pub naive: &'static str,
+    pub optimized: &'static str,
+    /// Size of the generated inputs, small enough for the naive solver.
+    pub size: usize,
+    /// Results of the naive and the optimized solver on an input.
+    solve: fn(&str) -> (String, String),
+}
+
+pub static PAIRS: [Pair; 3] = [
+    Pair {
+        day: 13,
+        naive: "ch13::find_time",
+        optimized: "ch13::solve_extended_euclidean",
+        size: 20,
+        solve: ch13_departures,
+    },
+    Pair {
+        day: 19,
+        naive: "ch19::compute_possible_rules",
+        optimized: "ch19::match_rec",
+        size: 5,
+        solve: ch19_messages,
+    },
+    Pair {
+        day: 23,
+        naive: "ch23::Game",
+        optimized: "ch23::Deck",
+        size: 9,
+        solve: ch23_cups,
+    },
+];
+
+/// An input on which the solvers of a pair give different results.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub pair: &'static Pair,
+    pub seed: u64,
+    pub naive: String,
+    pub optimized: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}: {} gives {} but {} gives {} (input: generate {} --seed {} --size {})",
+            self.pair.day,
+            self.pair.naive,
+            self.naive,
+            self.pair.optimized,
+            self.optimized,
+            self.pair.day,
+            self.seed,
+            self.pair.size
+        )
+    }
+}
+
+impl Pair {
+    /// Runs both solvers on the input generated from `seed`.
+    pub fn compare(&'static self, seed: u64) -> Result<(), Disagreement> {
+        let input = generate::generate(self.day, seed, self.size).unwrap();
+        let (naive, optimized) = (self.solve)(&input);
+
+        if naive == optimized {
+            Ok(())
+        } else {
+            Err(Disagreement {
+                pair: self,
+                seed,
+                naive,
+                optimized,
+            })
+        }
+    }
+}
+
+/// The pairs of `day`, or all of them if `None`.
+pub fn pairs(day: Option<u8>) -> impl Iterator<Item = &'static Pair> {
+    PAIRS
+        .iter()
+        .filter(move |pair| day.is_none_or(|day| pair.day == day))
+}
+
+/// Compares the solvers of every pair of `day`, or of all pairs if `None`, on the inputs
+/// generated from `seeds`.
+pub fn check(day: Option<u8>, seeds: Range<u64>) -> Vec<Disagreement> {
+    pairs(day)
+        .flat_map(|pair| {
+            seeds
+                .clone()
+                .filter_map(move |seed| pair.compare(seed).err())
+        })
+        .collect()
+}
+
+fn ch13_departures(input: &str) -> (String, String) {
+    let (_, buses) = ch13::Day.parse(input).unwrap();
+
+    // the brute force takes up to the product of the ids divided by the largest one
+    let mut product = 1;
+    let buses: Vec<(i64, i64)> = buses
+        .into_iter()
+        .take_while(|&(_, bus)| {
+            product *= bus;
+            product <= 1_000_000
+        })
+        .collect();
+
+    (
+        ch13::find_time(&buses).to_string(),
+        ch13::solve_extended_euclidean(&buses).to_string(),
+    )
+}
+
+fn ch19_messages(input: &str) -> (String, String) {
+    let (rules, messages) = ch19::Day.parse(input).unwrap();
+    let unrolled = ch19::compute_possible_rules();
+
+    let matching = |matches: &dyn Fn(&str) -> bool| {
+        let indices: Vec<usize> = (0..messages.len())
+            .filter(|&idx| matches(&messages[idx]))
+            .collect();
+        format!("matching messages {:?}", indices)
+    };
+
+    (
+        matching(&|message| ch19::matched_any_full(message, &unrolled, &rules)),
+        matching(&|message| ch19::match_rec(message, &rules)),
+    )
+}
+
+fn ch23_cups(input: &str) -> (String, String) {
+    const MOVES: usize = 100;
+    let labels = ch23::Day.parse(input).unwrap();
+
+    let mut game = ch23::Game::new(&labels);
+    for _ in 0..MOVES {
+        game.iterate();
+    }
+    let mut deck = ch23::Deck::new(&labels, labels.len());
+    deck.play(MOVES);
+
+    (game.value(), deck.value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs_agree() {
+        let disagreements = check(None, 0..10);
+        let messages: Vec<String> = disagreements.iter().map(|d| d.to_string()).collect();
+        assert!(messages.is_empty(), "{:#?}", messages);
+    }
+
+    #[test]
+    fn test_disagreement_display() {
+        let disagreement = Disagreement {
+            pair: &PAIRS[2],
+            seed: 4,
+            naive: String::from("12345678"),
+            optimized: String::from("87654321"),
+        };
+        assert_eq!(
+            disagreement.to_string(),
+            "day 23: ch23::Game gives 12345678 but ch23::Deck gives 87654321 \
+             (input: generate 23 --seed 4 --size 9)"
+        );
+    }
+
+    #[test]
+    fn test_pairs() {
+        assert_eq!(pairs(None).count(), 3);
+        assert_eq!(
+            pairs(Some(19)).map(|pair| pair.naive).collect::<Vec<_>>(),
+            vec!["ch19::compute_possible_rules"]
+        );
+        assert_eq!(pairs(Some(5)).count(), 0);
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+
+/// Errors reported by the runner and by the day parsers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Malformed puzzle input. `line` and `column` are 1-based, `text` is the offending part of
+    /// the line.
+    Parse {
+        day: Option<u8>,
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// The puzzle input could not be read.
+    Input(String),
+    UnknownYear(u16),
+    UnknownDay(u8),
+    MissingPart {
+        day: u8,
+        part: u8,
+    },
+    /// A solver panicked, while parsing if `part` is `None`.
+    Panicked {
+        day: u8,
+        part: Option<u8>,
+    },
+}
+
+/// Column (1-based, in characters) of `text` inside `line`.
+///
+/// `text` is normally a slice of `line`, otherwise its first occurrence is used.
+fn column_of(line: &str, text: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|&offset| line.get(offset..offset + text.len()) == Some(text))
+        .or_else(|| line.find(text))
+        .unwrap_or(0);
+
+    line[..offset].chars().count() + 1
+}
+
+impl Error {
+    /// Error about `text` found on the first line of `line`; callers parsing several lines move
+    /// it to the right line with [`Error::offset_lines`].
+    pub fn parse<S: Into<String>>(line: &str, text: &str, message: S) -> Error {
+        Error::Parse {
+            day: None,
+            line: 1,
+            column: column_of(line, text),
+            text: String::from(text),
+            message: message.into(),
+        }
+    }
+
+    /// Error for input that stops before something required was found.
+    pub fn end_of_input<S: Into<String>>(input: &str, message: S) -> Error {
+        Error::Parse {
+            day: None,
+            line: input.lines().count() + 1,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Moves a parse error down by `lines` lines.
+    pub fn offset_lines(self, lines: usize) -> Error {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                text,
+                message,
+            } => Error::Parse {
+                day,
+                line: line + lines,
+                column,
+                text,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Error {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                text,
+                message,
+                ..
+            } => Error::Parse {
+                day: Some(day),
+                line,
+                column,
+                text,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                if let Some(day) = day {
+                    write!(f, "day {}, ", day)?;
+                }
+                write!(f, "line {}, column {}: {}", line, column, message)?;
+                if !text.is_empty() {
+                    write!(f, ": '{}'", text)?;
+                }
+                Ok(())
+            }
+            Error::Input(message) => write!(f, "{}", message),
+            Error::UnknownYear(year) => write!(f, "no solutions for {}", year),
+            Error::UnknownDay(day) => write!(f, "unknown day {}", day),
+            Error::MissingPart { day, part } => write!(f, "day {} has no part {}", day, part),
+            Error::Panicked { day, part: None } => write!(f, "day {} panicked while parsing", day),
+            Error::Panicked {
+                day,
+                part: Some(part),
+            } => write!(f, "day {} part {} panicked", day, part),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_column() {
+        let line = "jmp +3 xyz";
+
+        assert_eq!(column_of(line, &line[7..]), 8);
+        assert_eq!(column_of(line, "+3"), 5);
+        assert_eq!(column_of(line, "missing"), 1);
+        assert_eq!(column_of("äb c", &"äb c"[4..]), 4);
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let line = "nop +0\nfoo +1";
+        let second = line.lines().nth(1).unwrap();
+        let error = Error::parse(second, &second[..3], "unknown operation")
+            .offset_lines(1)
+            .with_day(8);
+
+        assert_eq!(
+            error.to_string(),
+            "day 8, line 2, column 1: unknown operation: 'foo'"
+        );
+        assert_eq!(
+            Error::end_of_input(line, "missing accumulator").to_string(),
+            "line 3, column 1: missing accumulator"
+        );
+    }
+}
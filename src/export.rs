@@ -0,0 +1,266 @@
+//! Pictures of the grids of the puzzles, as PPM or SVG files.
+//!
+//! The days of 2020 with a grid render it as a [`Grid`] of [`Color`]s, one per cell: a single
+//! frame for a still map, or one frame per generation for a simulation. ch12 draws the route of
+//! the ship on a grid of its own, one frame per instruction. [`write_frames`] then encodes each
+//! frame, every cell drawn as a square of `scale` pixels, into numbered files that can be looked
+//! at one by one or assembled into an animation.
+
+use crate::error::Error;
+use crate::grid::Grid;
+use crate::y2020::{ch03, ch11, ch12, ch17, ch20, ch24};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Pixels on each side of a cell, unless told otherwise.
+pub const DEFAULT_SCALE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const WHITE: Color = Color(255, 255, 255);
+    pub const BLACK: Color = Color(0, 0, 0);
+    /// Separates the parts of a frame made of several grids.
+    pub const GUTTER: Color = Color(200, 200, 200);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Binary portable pixmap (P6).
+    Ppm,
+    /// Scalable vector graphics, a rectangle per run of cells of the same color on a row.
+    Svg,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+type Renderer = fn(&str, u8) -> Result<Vec<Grid<Color>>, Error>;
+
+static RENDERERS: [(u8, Renderer); 6] = [
+    (3, ch03::frames),
+    (11, ch11::frames),
+    (12, ch12::frames),
+    (17, ch17::frames),
+    (20, ch20::frames),
+    (24, ch24::frames),
+];
+
+/// Days with a grid to export.
+pub fn days() -> impl Iterator<Item = u8> {
+    RENDERERS.iter().map(|&(day, _)| day)
+}
+
+/// The frames of part `part` of `day` on `input`.
+pub fn frames(day: u8, input: &str, part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    match RENDERERS.iter().find(|&&(d, _)| d == day) {
+        Some((_, renderer)) => renderer(input, part),
+        None => Err(Error::Input(format!("day {} has no grid to export", day))),
+    }
+}
+
+pub fn encode(frame: &Grid<Color>, format: Format, scale: usize) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+
+    match format {
+        Format::Ppm => {
+            let mut bytes = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+            for row in frame.rows() {
+                let mut line = Vec::with_capacity(width * 3);
+                for &Color(r, g, b) in row {
+                    for _ in 0..scale {
+                        line.extend_from_slice(&[r, g, b]);
+                    }
+                }
+                for _ in 0..scale {
+                    bytes.extend_from_slice(&line);
+                }
+            }
+            bytes
+        }
+        Format::Svg => {
+            let mut svg = format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+                 viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+                width,
+                height,
+                frame.width(),
+                frame.height()
+            );
+            for (y, row) in frame.rows().enumerate() {
+                let mut x = 0;
+                while x < row.len() {
+                    let Color(r, g, b) = row[x];
+                    let run = row[x..].iter().take_while(|&&c| c == row[x]).count();
+                    let _ = writeln!(
+                        svg,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" \
+                         fill=\"#{:02x}{:02x}{:02x}\"/>",
+                        x, y, run, r, g, b
+                    );
+                    x += run;
+                }
+            }
+            svg.push_str("</svg>\n");
+            svg.into_bytes()
+        }
+    }
+}
+
+/// Writes `frames` as `<directory>/<name>-NNNN.<extension>`, creating the directory if needed.
+/// Returns the paths written.
+pub fn write_frames(
+    frames: &[Grid<Color>],
+    directory: &Path,
+    name: &str,
+    format: Format,
+    scale: usize,
+) -> Result<Vec<PathBuf>, Error> {
+    let error = |path: &Path, e: std::io::Error| {
+        Error::Input(format!("could not write {}: {}", path.display(), e))
+    };
+
+    fs::create_dir_all(directory).map_err(|e| error(directory, e))?;
+    let mut paths = Vec::with_capacity(frames.len());
+    for (idx, frame) in frames.iter().enumerate() {
+        let path = directory.join(format!("{}-{:04}.{}", name, idx, format.extension()));
+        fs::write(&path, encode(frame, format, scale)).map_err(|e| error(&path, e))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Lays `grids` out side by side on rows of `columns` grids, one cell of [`Color::GUTTER`]
+/// apart. Grids smaller than the largest one are padded with `background`.
+pub fn tile(grids: &[Grid<Color>], columns: usize, background: Color) -> Grid<Color> {
+    let columns = columns.max(1);
+    let rows = grids.len().div_ceil(columns);
+    let cell_width = grids.iter().map(|g| g.width()).max().unwrap_or(0) + 1;
+    let cell_height = grids.iter().map(|g| g.height()).max().unwrap_or(0) + 1;
+
+    Grid::from_fn(
+        (columns * cell_width).saturating_sub(1),
+        (rows * cell_height).saturating_sub(1),
+        |x, y| {
+            let (column, row) = (x / cell_width, y / cell_height);
+            let (x, y) = (x % cell_width, y % cell_height);
+            if x == cell_width - 1 || y == cell_height - 1 {
+                return Color::GUTTER;
+            }
+            grids
+                .get(row * columns + column)
+                .and_then(|grid| grid.get(x, y))
+                .copied()
+                .unwrap_or(background)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Grid<Color> {
+        Grid::from_fn(2, 2, |x, y| {
+            if (x + y) % 2 == 0 {
+                Color::BLACK
+            } else {
+                Color::WHITE
+            }
+        })
+    }
+
+    #[test]
+    fn test_encode_ppm() {
+        let bytes = encode(&checkerboard(), Format::Ppm, 2);
+
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&bytes[..header.len()], header);
+        let pixels = &bytes[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        // first row: two black pixels, then two white ones, the same again on the second row
+        assert_eq!(
+            &pixels[..12],
+            &[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+        assert_eq!(&pixels[12..24], &pixels[..12]);
+        assert_eq!(&pixels[24..27], &[255, 255, 255]);
+    }
+
+    #[test]
+    fn test_encode_svg() {
+        let frame = Grid::from_fn(
+            3,
+            1,
+            |x, _| if x < 2 { Color::BLACK } else { Color(1, 2, 3) },
+        );
+        let svg = String::from_utf8(encode(&frame, Format::Svg, 5)).unwrap();
+
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"15\" height=\"5\"")
+        );
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\"/>"));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#010203\"/>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_tile() {
+        let small = Grid::new(1, 1, Color::BLACK);
+        let tiled = tile(&[checkerboard(), small.clone(), small], 2, Color::WHITE);
+
+        assert_eq!((tiled.width(), tiled.height()), (5, 5));
+        assert_eq!(tiled[(2, 0)], Color::GUTTER);
+        assert_eq!(tiled[(3, 0)], Color::BLACK);
+        assert_eq!(tiled[(4, 1)], Color::WHITE);
+        assert_eq!(tiled[(0, 3)], Color::BLACK);
+        // no grid left for the last place
+        assert_eq!(tiled[(3, 3)], Color::WHITE);
+    }
+
+    #[test]
+    fn test_frames_of_every_day() {
+        for day in days() {
+            let input = crate::generate::generate(day, 1, 5).unwrap();
+            for part in 1..=2 {
+                let frames = frames(day, &input, part).unwrap();
+                assert!(!frames.is_empty(), "day {} part {}", day, part);
+            }
+        }
+        assert!(frames(5, "", 1).is_err());
+    }
+
+    #[test]
+    fn test_write_frames() {
+        let directory =
+            std::env::temp_dir().join(format!("code-advent-2020-export-{}", std::process::id()));
+        let paths = write_frames(
+            &[checkerboard(), checkerboard()],
+            &directory,
+            "day11",
+            Format::Svg,
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(
+            paths,
+            vec![
+                directory.join("day11-0000.svg"),
+                directory.join("day11-0001.svg")
+            ]
+        );
+        assert!(fs::read_to_string(&paths[1]).unwrap().starts_with("<svg"));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
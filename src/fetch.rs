@@ -0,0 +1,411 @@
+//! Puzzle inputs downloaded from the puzzle site and kept in a per-user cache.
+//!
+//! Inputs differ from one account to the next, so the site only hands them out to a logged in
+//! session: [`Fetcher`] sends the session token as a cookie. Each input is downloaded once, then
+//! read back from the [`Cache`].
+//!
+//! The endpoint is configurable so that a local stub can stand in for the site. Plain `http://`
+//! endpoints are spoken to directly; `https://` ones go through `curl`, the standard library
+//! having no TLS.
+
+use crate::error::Error;
+use std::env;
+use std::fs;
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Site the inputs are downloaded from, unless configured otherwise.
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable that overrides [`DEFAULT_ENDPOINT`].
+pub const ENDPOINT_VAR: &str = "AOC_ENDPOINT";
+
+/// Environment variable that overrides the cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE";
+
+const USER_AGENT: &str = "code-advent-2020 input fetcher";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Downloaded inputs, laid out as `<dir>/<year>/dayNN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    /// The cache of the current user: `$AOC_CACHE`, else `code-advent-2020` in
+    /// `$XDG_CACHE_HOME` or `~/.cache`. `None` if there is no home directory to put it in.
+    pub fn user() -> Option<Cache> {
+        if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+            return Some(Cache::new(dir));
+        }
+        let base = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+        Some(Cache::new(base.join("code-advent-2020")))
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The cached input of `day` of `year`, `None` if it was not downloaded yet.
+    pub fn read(&self, year: u16, day: u8) -> Result<Option<String>, Error> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::Input(format!(
+                "could not read cached input {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    pub fn store(&self, year: u16, day: u8, contents: &str) -> Result<PathBuf, Error> {
+        let path = self.path(year, day);
+        let error = |e: std::io::Error| {
+            Error::Input(format!("could not cache input {}: {}", path.display(), e))
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+        // a download interrupted halfway must not pass for an input
+        let temporary = path.with_extension("txt.tmp");
+        fs::write(&temporary, contents).map_err(error)?;
+        fs::rename(&temporary, &path).map_err(error)?;
+        Ok(path)
+    }
+}
+
+/// Downloads inputs on behalf of a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetcher {
+    endpoint: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(endpoint: &str, session: &str) -> Fetcher {
+        Fetcher {
+            endpoint: String::from(endpoint.trim_end_matches('/')),
+            session: String::from(session.trim()),
+        }
+    }
+
+    /// The fetcher of the session in `$AOC_SESSION`, from `$AOC_ENDPOINT` or the puzzle site.
+    /// `None` if there is no session.
+    pub fn from_env() -> Option<Fetcher> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|s| !s.trim().is_empty())?;
+        let endpoint = env::var(ENDPOINT_VAR).unwrap_or_else(|_| String::from(DEFAULT_ENDPOINT));
+        Some(Fetcher::new(&endpoint, &session))
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.endpoint, year, day)
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = self.url(year, day);
+        let error = |message: String| {
+            Error::Input(format!(
+                "could not download the input of {} day {} from {}: {}",
+                year, day, url, message
+            ))
+        };
+
+        let (status, body) = if let Some(rest) = url.strip_prefix("http://") {
+            get(rest, &self.session).map_err(error)?
+        } else if url.starts_with("https://") {
+            curl(&url, &self.session).map_err(error)?
+        } else {
+            return Err(error(String::from("only http and https are supported")));
+        };
+
+        match status {
+            200 => Ok(body),
+            _ => {
+                // the site explains what went wrong, e.g. that the session is not logged in
+                let reason = body.lines().next().unwrap_or_default();
+                Err(error(
+                    format!("status {} {}", status, reason).trim().to_string(),
+                ))
+            }
+        }
+    }
+}
+
+/// Sends a GET request for `location`, a URL without its `http://`, and returns the status and
+/// body of the response.
+fn get(location: &str, session: &str) -> Result<(u16, String), String> {
+    let (authority, path) = match location.find('/') {
+        Some(idx) => location.split_at(idx),
+        None => (location, "/"),
+    };
+    let address = if authority.contains(':') {
+        String::from(authority)
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let mut stream = TcpStream::connect(&address).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        .map_err(|e| e.to_string())?;
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n\
+         Connection: close\r\n\r\n",
+        path, authority, USER_AGENT, session
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .map_err(|e| e.to_string())?;
+    parse_response(&response)
+}
+
+/// Status and body of an HTTP/1.1 response.
+fn parse_response(response: &[u8]) -> Result<(u16, String), String> {
+    let invalid = || String::from("invalid response");
+    let split = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(invalid)?;
+    let head = std::str::from_utf8(&response[..split]).map_err(|_| invalid())?;
+    let mut body = &response[split + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(invalid)?;
+
+    let mut chunked = false;
+    for line in lines {
+        let (name, value) = line.split_once(':').ok_or_else(invalid)?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            let length: usize = value.parse().map_err(|_| invalid())?;
+            body = body.get(..length).ok_or_else(invalid)?;
+        }
+    }
+
+    let body = if chunked {
+        dechunk(body).ok_or_else(invalid)?
+    } else {
+        body.to_vec()
+    };
+    let body = String::from_utf8(body).map_err(|_| invalid())?;
+    Ok((status, body))
+}
+
+/// The body of a response sent in chunks, each one preceded by its size in hexadecimal.
+fn dechunk(mut chunks: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let end = chunks.windows(2).position(|window| window == b"\r\n")?;
+        let size = std::str::from_utf8(&chunks[..end]).ok()?;
+        // chunk extensions follow a ';'
+        let size = size.split(';').next()?.trim();
+        let size = usize::from_str_radix(size, 16).ok()?;
+        if size == 0 {
+            return Some(body);
+        }
+        let start = end + 2;
+        body.extend_from_slice(chunks.get(start..start + size)?);
+        chunks = chunks.get(start + size + 2..)?;
+    }
+}
+
+/// Command fetching `url` with curl, reading the session cookie from a config on stdin so that
+/// it does not show in the list of processes.
+fn curl_command(url: &str) -> Command {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--location"])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--config", "-"])
+        .args(["--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    command
+}
+
+/// curl config sending the session cookie.
+fn curl_config(session: &str) -> String {
+    let quoted = session.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("cookie = \"session={}\"\n", quoted)
+}
+
+fn curl(url: &str, session: &str) -> Result<(u16, String), String> {
+    let mut child = curl_command(url)
+        .spawn()
+        .map_err(|e| format!("https needs curl: {}", e))?;
+    // dropping stdin once written closes it, for curl to go on with the request
+    child
+        .stdin
+        .take()
+        .expect("stdin of curl is piped")
+        .write_all(curl_config(session).as_bytes())
+        .map_err(|e| e.to_string())?;
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    // the status comes last, on a line of its own
+    let output = String::from_utf8(output.stdout).map_err(|_| String::from("invalid response"))?;
+    let (body, status) = output
+        .rsplit_once('\n')
+        .ok_or_else(|| String::from("invalid response"))?;
+    let status = status
+        .parse()
+        .map_err(|_| String::from("invalid response"))?;
+    Ok((status, String::from(body)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers one request with `response`, and returns the request it got.
+    fn stub(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let len = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..len]);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (endpoint, server)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (endpoint, server) =
+            stub("HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\n1721\n979\nignored");
+        let fetcher = Fetcher::new(&format!("{}/", endpoint), "53cr3t\n");
+
+        assert_eq!(fetcher.fetch(2020, 1).unwrap(), "1721\n979\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2020/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=53cr3t\r\n"));
+    }
+
+    #[test]
+    fn test_curl_session_not_in_arguments() {
+        let url = "https://adventofcode.com/2020/day/1/input";
+        let command = curl_command(url);
+        let args: Vec<_> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy())
+            .collect();
+        assert!(args.iter().any(|arg| arg == url));
+        assert!(!args.iter().any(|arg| arg.contains("session")));
+
+        assert_eq!(curl_config("53cr3t"), "cookie = \"session=53cr3t\"\n");
+        assert_eq!(curl_config("a\"b\\c"), "cookie = \"session=a\\\"b\\\\c\"\n");
+
+        // the cookie still reaches the server, when there is a curl to send it
+        let (endpoint, server) = stub("HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\n42\n");
+        match curl(&format!("{}/2020/day/1/input", endpoint), "53cr3t") {
+            Err(e) if e.starts_with("https needs curl") => {}
+            response => {
+                assert_eq!(response, Ok((200, String::from("42\n"))));
+                assert!(server
+                    .join()
+                    .unwrap()
+                    .contains("\r\nCookie: session=53cr3t\r\n"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (endpoint, server) =
+            stub("HTTP/1.1 400 Bad Request\r\nContent-Length: 18\r\n\r\nPlease log in.\nNow");
+        let error = Fetcher::new(&endpoint, "expired")
+            .fetch(2020, 6)
+            .unwrap_err();
+        server.join().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "could not download the input of 2020 day 6 from {}/2020/day/6/input: \
+                 status 400 Please log in.",
+                endpoint
+            )
+        );
+        assert!(Fetcher::new("ftp://example.com", "token")
+            .fetch(2020, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(
+                b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n0,3,\r\n2;x=y\r\n6\n\r\n0\r\n\r\n"
+            ),
+            Ok((200, String::from("0,3,6\n")))
+        );
+        assert_eq!(
+            parse_response(b"HTTP/1.1 404 Not Found\r\n\r\n"),
+            Ok((404, String::new()))
+        );
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort").is_err());
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = env::temp_dir().join(format!("code-advent-2020-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+
+        assert_eq!(cache.path(2020, 7), dir.join("2020").join("day07.txt"));
+        assert_eq!(cache.read(2020, 7).unwrap(), None);
+        cache
+            .store(2020, 7, "light red bags contain no other bags.\n")
+            .unwrap();
+        assert_eq!(
+            cache.read(2020, 7).unwrap().as_deref(),
+            Some("light red bags contain no other bags.\n")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
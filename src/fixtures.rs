@@ -0,0 +1,252 @@
+//! Worked examples from the puzzle descriptions, together with their expected answers.
+//!
+//! Each day keeps its examples in `src/yYYYY/chNN/examples.txt`, listed by its year. An example
+//! starts with one or more `== part N: answer` header lines and its input follows, up to the next
+//! header:
+//!
+//! ```text
+//! == part 1: 514579
+//! == part 2: 241861950
+//! 1721
+//! 979
+//! ```
+//!
+//! Parts without a header are not checked for that example, e.g. because the puzzle did not
+//! give an answer for it.
+
+use crate::error::Error;
+use crate::y2020;
+
+const HEADER_PREFIX: &str = "== ";
+
+static YEARS: [(u16, &[&str]); 1] = [(y2020::YEAR, &y2020::EXAMPLES)];
+
+/// One worked example: its input and the answers the puzzle gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// 1-based line of the first header of the example in its fixture file.
+    pub line: usize,
+    pub input: String,
+    pub expected: Vec<(u8, String)>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.expected
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.as_str())
+    }
+}
+
+/// Raw text of the fixture file of `day` of `year`.
+pub fn fixture(year: u16, day: u8) -> Option<&'static str> {
+    let (_, fixtures) = YEARS.iter().find(|&&(y, _)| y == year)?;
+    let index = usize::from(day).checked_sub(1)?;
+    fixtures.get(index).copied()
+}
+
+fn parse_header(line: &str) -> Result<(u8, String), Error> {
+    let header = &line[HEADER_PREFIX.len()..];
+    let (part, answer) = header
+        .strip_prefix("part ")
+        .and_then(|h| {
+            let colon = h.find(':')?;
+            Some((&h[..colon], h[colon + 1..].trim()))
+        })
+        .ok_or_else(|| Error::parse(line, header, "expected a header like '== part 1: 42'"))?;
+
+    let part = part
+        .parse()
+        .map_err(|_| Error::parse(line, part, "invalid part"))?;
+    if answer.is_empty() {
+        return Err(Error::parse(line, line, "missing answer"));
+    }
+
+    Ok((part, String::from(answer)))
+}
+
+/// Splits the text of a fixture file into its examples.
+pub fn parse(text: &str) -> Result<Vec<Example>, Error> {
+    let mut examples: Vec<Example> = Vec::new();
+    let mut in_header = false;
+
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim_end();
+
+        if line.starts_with(HEADER_PREFIX) {
+            let expected = parse_header(line).map_err(|e| e.offset_lines(idx))?;
+            match examples.last_mut() {
+                Some(example) if in_header => example.expected.push(expected),
+                _ => examples.push(Example {
+                    line: idx + 1,
+                    input: String::new(),
+                    expected: vec![expected],
+                }),
+            }
+            in_header = true;
+        } else {
+            let example = examples
+                .last_mut()
+                .ok_or_else(|| Error::parse(line, line, "input before the first header"))
+                .map_err(|e| e.offset_lines(idx))?;
+            example.input.push_str(line);
+            example.input.push('\n');
+            in_header = false;
+        }
+    }
+
+    for example in &mut examples {
+        let len = example.input.trim_end().len();
+        example.input.truncate(len);
+    }
+
+    Ok(examples)
+}
+
+/// The worked examples of `day` of `year`.
+pub fn examples(year: u16, day: u8) -> Result<Vec<Example>, Error> {
+    if !YEARS.iter().any(|&(y, _)| y == year) {
+        return Err(Error::UnknownYear(year));
+    }
+    let text = fixture(year, day).ok_or(Error::UnknownDay(day))?;
+    parse(text).map_err(|e| e.with_day(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{self, DynSolution};
+    use crate::y2020::{ch09, YEAR};
+
+    #[test]
+    fn test_parse() {
+        let examples = parse("== part 1: 7\n== part 2: a,b\n1\n\n2\n\n== part 2: 3\n4\n").unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    line: 1,
+                    input: String::from("1\n\n2"),
+                    expected: vec![(1, String::from("7")), (2, String::from("a,b"))],
+                },
+                Example {
+                    line: 7,
+                    input: String::from("4"),
+                    expected: vec![(2, String::from("3"))],
+                },
+            ]
+        );
+        assert_eq!(examples[1].expected(1), None);
+        assert_eq!(examples[1].expected(2), Some("3"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            parse("1\n== part 1: 2"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            parse("== part 1: 2\n1\n== part one: 2"),
+            Err(Error::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            parse("== part 1:\n1"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_examples() {
+        assert!(examples(YEAR, 0).is_err());
+        assert!(examples(YEAR, 26).is_err());
+        assert_eq!(examples(2019, 1), Err(Error::UnknownYear(2019)));
+        for (day, _) in solution::solutions(YEAR) {
+            assert!(!examples(YEAR, day).unwrap().is_empty(), "day {}", day);
+        }
+    }
+
+    /// Solves `part` of every example of `day` that has an answer for it. If none has, the day
+    /// must not have that part at all.
+    fn check(day_solution: &dyn DynSolution, day: u8, part: u8) {
+        let examples = examples(YEAR, day).unwrap();
+
+        let mut checked = 0;
+        for example in &examples {
+            if let Some(expected) = example.expected(part) {
+                let input = day_solution
+                    .parse_input(&example.input)
+                    .unwrap_or_else(|e| panic!("example at line {}: {}", example.line, e));
+                let answer = day_solution.solve_part(input.as_ref(), part);
+
+                assert_eq!(
+                    answer.map(|a| a.to_string()).as_deref(),
+                    Some(expected),
+                    "example at line {}",
+                    example.line
+                );
+                checked += 1;
+            }
+        }
+
+        if checked == 0 {
+            let input = day_solution.parse_input(&examples[0].input).unwrap();
+            assert_eq!(day_solution.solve_part(input.as_ref(), part), None);
+        }
+    }
+
+    /// Tests of both parts of each day against its examples. Attributes before a day go on the
+    /// test of its part 2, to ignore the ones running the full puzzle.
+    macro_rules! fixture_tests {
+        ($($(#[$part2:meta])* $name:ident: $day:expr => $solution:expr),* $(,)?) => {
+            $(
+                mod $name {
+                    use super::*;
+
+                    #[test]
+                    fn part1() {
+                        check($solution, $day, 1);
+                    }
+
+                    #[test]
+                    $(#[$part2])*
+                    fn part2() {
+                        check($solution, $day, 2);
+                    }
+                }
+            )*
+        };
+    }
+
+    fixture_tests! {
+        day01: 1 => solution::solution(YEAR, 1).unwrap(),
+        day02: 2 => solution::solution(YEAR, 2).unwrap(),
+        day03: 3 => solution::solution(YEAR, 3).unwrap(),
+        day04: 4 => solution::solution(YEAR, 4).unwrap(),
+        day05: 5 => solution::solution(YEAR, 5).unwrap(),
+        day06: 6 => solution::solution(YEAR, 6).unwrap(),
+        day07: 7 => solution::solution(YEAR, 7).unwrap(),
+        day08: 8 => solution::solution(YEAR, 8).unwrap(),
+        day09: 9 => &ch09::Day { preamble: 5 },
+        day10: 10 => solution::solution(YEAR, 10).unwrap(),
+        day11: 11 => solution::solution(YEAR, 11).unwrap(),
+        day12: 12 => solution::solution(YEAR, 12).unwrap(),
+        day13: 13 => solution::solution(YEAR, 13).unwrap(),
+        day14: 14 => solution::solution(YEAR, 14).unwrap(),
+        #[ignore = "plays the 30 million turns of the puzzle, run with --ignored"]
+        day15: 15 => solution::solution(YEAR, 15).unwrap(),
+        day16: 16 => solution::solution(YEAR, 16).unwrap(),
+        day17: 17 => solution::solution(YEAR, 17).unwrap(),
+        day18: 18 => solution::solution(YEAR, 18).unwrap(),
+        day19: 19 => solution::solution(YEAR, 19).unwrap(),
+        day20: 20 => solution::solution(YEAR, 20).unwrap(),
+        day21: 21 => solution::solution(YEAR, 21).unwrap(),
+        day22: 22 => solution::solution(YEAR, 22).unwrap(),
+        #[ignore = "plays the 10 million moves of the puzzle, run with --ignored"]
+        day23: 23 => solution::solution(YEAR, 23).unwrap(),
+        day24: 24 => solution::solution(YEAR, 24).unwrap(),
+        day25: 25 => solution::solution(YEAR, 25).unwrap(),
+    }
+}
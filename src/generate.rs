@@ -0,0 +1,204 @@
+//! Random puzzle inputs of the days of 2020, for stress tests and property tests beyond the one
+//! input of each day.
+//!
+//! Generation is deterministic: the same day, seed and size always give the same input. Each
+//! day builds its input around a planted solution, so the inputs are not only well-formed but
+//! solvable, e.g. the ch01 report always holds two and three entries adding up to 2020.
+//!
+//! The size is counted in the natural unit of the day (report entries for ch01, passports for
+//! ch04, bag rules for ch07, instructions for ch08, tiles for ch20, ...), see
+//! [`default_size`]. Days whose input cannot grow much clamp it, like the 5 to 9 cups of ch23.
+
+use crate::y2020::{
+    ch01, ch02, ch03, ch04, ch05, ch06, ch07, ch08, ch09, ch10, ch11, ch12, ch13, ch14, ch15, ch16,
+    ch17, ch18, ch19, ch20, ch21, ch22, ch23, ch24, ch25,
+};
+use std::collections::HashSet;
+
+type Generator = fn(&mut Rng, usize) -> String;
+
+static GENERATORS: [(Generator, usize); 25] = [
+    (ch01::generate, 200),
+    (ch02::generate, 1000),
+    (ch03::generate, 323),
+    (ch04::generate, 290),
+    (ch05::generate, 800),
+    (ch06::generate, 480),
+    (ch07::generate, 590),
+    (ch08::generate, 650),
+    (ch09::generate, 1000),
+    (ch10::generate, 100),
+    (ch11::generate, 95),
+    (ch12::generate, 780),
+    (ch13::generate, 90),
+    (ch14::generate, 570),
+    (ch15::generate, 6),
+    (ch16::generate, 240),
+    (ch17::generate, 8),
+    (ch18::generate, 370),
+    (ch19::generate, 450),
+    (ch20::generate, 144),
+    (ch21::generate, 40),
+    (ch22::generate, 25),
+    (ch23::generate, 9),
+    (ch24::generate, 370),
+    (ch25::generate, 5_000_000),
+];
+
+/// A random input for `day`, `None` if there is no such day.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let (generator, _) = GENERATORS.get(usize::from(day).checked_sub(1)?)?;
+    Some(generator(&mut Rng::new(seed), size))
+}
+
+/// Size of the puzzle inputs of `day`, in the unit its generator counts.
+pub fn default_size(day: u8) -> Option<usize> {
+    let (_, size) = GENERATORS.get(usize::from(day).checked_sub(1)?)?;
+    Some(*size)
+}
+
+/// Small and fast pseudo-random number generator (SplitMix64). Not suitable for cryptography.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(len) => low + ((u128::from(self.next_u64()) * u128::from(len)) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a slice of `len` elements.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index into an empty slice");
+        self.range(0, len as u64 - 1) as usize
+    }
+
+    /// `true` with a probability of `percent` in 100.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0, 99) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+
+    /// `count` different numbers of `low..=high`, in random order.
+    pub fn distinct(&mut self, low: u64, high: u64, count: usize) -> Vec<u64> {
+        if count == 0 {
+            return Vec::new();
+        }
+        assert!(
+            count as u64 - 1 <= high - low,
+            "not {} numbers in {}..={}",
+            count,
+            low,
+            high
+        );
+
+        // Floyd's algorithm: one draw per number, whatever the size of the range
+        let mut chosen = HashSet::new();
+        let mut numbers = Vec::with_capacity(count);
+        for top in (high - (count as u64 - 1))..=high {
+            let n = self.range(low, top);
+            let n = if chosen.insert(n) { n } else { top };
+            chosen.insert(n);
+            numbers.push(n);
+        }
+        self.shuffle(&mut numbers);
+        numbers
+    }
+
+    /// A lowercase ASCII letter.
+    pub fn letter(&mut self) -> char {
+        char::from(b'a' + self.range(0, 25) as u8)
+    }
+
+    /// A word of `low..=high` random lowercase letters.
+    pub fn word(&mut self, low: usize, high: usize) -> String {
+        let len = self.range(low as u64, high as u64);
+        (0..len).map(|_| self.letter()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution;
+    use crate::y2020::YEAR;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            assert!((3..=7).contains(&rng.range(3, 7)));
+        }
+        assert_eq!(rng.range(5, 5), 5);
+
+        let mut numbers = rng.distinct(10, 19, 10);
+        numbers.sort_unstable();
+        assert_eq!(numbers, (10..20).collect::<Vec<_>>());
+        assert_eq!(rng.distinct(0, u64::MAX, 3).len(), 3);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        for day in 1..=25 {
+            assert_eq!(generate(day, 7, 20), generate(day, 7, 20), "day {}", day);
+        }
+        assert_ne!(generate(4, 1, 20), generate(4, 2, 20));
+        assert_eq!(generate(0, 1, 20), None);
+        assert_eq!(generate(26, 1, 20), None);
+    }
+
+    /// Generated inputs parse and both parts solve them, for a few seeds and sizes.
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for (day, day_solution) in solution::solutions(YEAR) {
+            // inputs of the full size take too long to solve in debug builds
+            let sizes = match day {
+                25 => vec![1, 1000],
+                _ => vec![0, 1, 10],
+            };
+
+            for seed in 0..3 {
+                for &size in &sizes {
+                    let input = generate(day, seed, size).unwrap();
+                    let context = format!("day {} seed {} size {}", day, seed, size);
+
+                    let parsed = day_solution
+                        .parse_input(&input)
+                        .unwrap_or_else(|e| panic!("{}: {}\n{}", context, e, input));
+                    day_solution.solve_part(parsed.as_ref(), 1);
+                    // the second parts of days 15 and 23 take as long whatever the input
+                    if day != 15 && day != 23 {
+                        day_solution.solve_part(parsed.as_ref(), 2);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,554 @@
+//! Rectangular grids of cells, as found in most map-like puzzle inputs.
+//!
+//! Positions are `(x, y)` pairs: `x` is the column, growing to the right, and `y` the row,
+//! growing downwards, so `(0, 0)` is the first character of the first line of the input.
+//!
+//! Hexagonal grids have no bounds and are only [`HexTile`] coordinates, moved around by
+//! [`HexDirection`]s.
+
+use crate::error::Error;
+use crate::utils::parse_lines;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the 4 orthogonal neighbors: up, right, down, left.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 neighbors, clockwise starting from up.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid whose cells are computed from their position.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid made of the given rows, or `None` if they do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a map with one character per cell. All lines must have the same length.
+    pub fn parse<F>(input: &str, mut f: F) -> Result<Grid<T>, Error>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let rows = parse_lines(input, |l| {
+            let row = l
+                .char_indices()
+                .map(|(idx, c)| {
+                    f(c).ok_or_else(|| {
+                        Error::parse(l, &l[idx..idx + c.len_utf8()], "invalid character")
+                    })
+                })
+                .collect::<Result<Vec<T>, Error>>()?;
+
+            match width {
+                Some(width) if width != row.len() => {
+                    Err(Error::parse(l, l, format!("expected {} characters", width)))
+                }
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::from_rows(rows).unwrap()),
+            _ => Err(Error::end_of_input(input, "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Cell at a signed position, `None` outside of the grid.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.get(x as usize, y as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Cell at a position wrapped around the edges, as if the grid was repeated in all
+    /// directions. `None` if the grid is empty.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        self.get(x, y)
+    }
+
+    /// Cell at a position moved to the nearest edge if it is outside of the grid. `None` if the
+    /// grid is empty.
+    pub fn get_clamped(&self, x: isize, y: isize) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let x = x.max(0).min(self.width as isize - 1) as usize;
+        let y = y.max(0).min(self.height as isize - 1) as usize;
+        self.get(x, y)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `x`, top to bottom, none if there is no such column.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn count<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells.iter().filter(|&cell| predicate(cell)).count()
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn neighbors<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width as isize, self.height as isize);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            if nx >= 0 && ny >= 0 && nx < width && ny < height {
+                Some((nx as usize, ny as usize))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Positions of the orthogonal neighbors of `(x, y)` inside the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(x, y, &NEIGHBORS4)
+    }
+
+    /// Positions of the orthogonal and diagonal neighbors of `(x, y)` inside the grid.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(x, y, &NEIGHBORS8)
+    }
+
+    /// Positions seen from `(x, y)` looking in direction `(dx, dy)`, nearest first, up to the
+    /// edge of the grid. The starting position is not included.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (1..)
+            .map(move |step| (x as isize + dx * step, y as isize + dy * step))
+            .take_while(move |&(x, y)| x >= 0 && y >= 0 && x < width && y < height)
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// Writes the grid one row per line, each cell as the character given by `f`.
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            s.extend(row.iter().map(&mut f));
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// The grid rotated a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid rotated a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// The grid mirrored along its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y).expect("position outside of the grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The six neighbors of a tile of a hexagonal grid.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+pub const HEX_DIRECTIONS: [HexDirection; 6] = [
+    HexDirection::East,
+    HexDirection::NorthEast,
+    HexDirection::NorthWest,
+    HexDirection::West,
+    HexDirection::SouthWest,
+    HexDirection::SouthEast,
+];
+
+/// A tile of a hexagonal grid with pointy tops, in doubled coordinates: east and west neighbors
+/// are 2 apart on `x`, the other ones 1 apart on both `x` and `y`, so `x + y` is always even.
+#[derive(Debug, PartialEq, Hash, Eq, Clone, Copy)]
+pub struct HexTile {
+    x: i32,
+    y: i32,
+}
+
+impl HexTile {
+    pub fn new(x: i32, y: i32) -> HexTile {
+        debug_assert!((x + y) % 2 == 0, "({}, {}) is not a hex tile", x, y);
+        HexTile { x, y }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    pub fn neighbor(&self, direction: HexDirection) -> HexTile {
+        let mut neighbor_x = self.x;
+        let mut neighbor_y = self.y;
+
+        match direction {
+            HexDirection::East => {
+                neighbor_x += 2;
+            }
+            HexDirection::NorthEast => {
+                neighbor_x += 1;
+                neighbor_y += 1;
+            }
+            HexDirection::NorthWest => {
+                neighbor_x -= 1;
+                neighbor_y += 1;
+            }
+            HexDirection::West => {
+                neighbor_x -= 2;
+            }
+            HexDirection::SouthWest => {
+                neighbor_x -= 1;
+                neighbor_y -= 1;
+            }
+            HexDirection::SouthEast => {
+                neighbor_x += 1;
+                neighbor_y -= 1;
+            }
+        }
+
+        HexTile {
+            x: neighbor_x,
+            y: neighbor_y,
+        }
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = HexTile> + '_ {
+        HEX_DIRECTIONS.iter().map(move |d| self.neighbor(*d))
+    }
+
+    /// The tile reached by walking from the reference tile `(0, 0)`.
+    pub fn walk(directions: &[HexDirection]) -> HexTile {
+        let mut current = HexTile { x: 0, y: 0 };
+
+        for direction in directions {
+            current = current.neighbor(*direction);
+        }
+
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("ab\ncd\nef", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'b');
+        assert_eq!(grid[(0, 2)], 'e');
+        assert_eq!(grid.row(1), &['c', 'd']);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(grid.column(1).rev().collect::<String>(), "fdb");
+        assert_eq!(grid.column(2).count(), 0);
+        assert_eq!(Grid::<char>::from_fn(0, 2, |_, _| '.').column(0).count(), 0);
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let digits = |c: char| c.to_digit(10);
+
+        assert_eq!(
+            Grid::parse("12\n3x", digits).unwrap_err(),
+            Error::parse("3x", "x", "invalid character").offset_lines(1)
+        );
+        assert_eq!(
+            Grid::parse("12\n345", digits).unwrap_err(),
+            Error::parse("345", "345", "expected 2 characters").offset_lines(1)
+        );
+        assert!(Grid::parse("", digits).is_err());
+    }
+
+    #[test]
+    fn test_indexing() {
+        let grid = sample();
+
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 2), Some(&'f'));
+        assert_eq!(grid.get_wrapping(2, 3), Some(&'a'));
+        assert_eq!(grid.get_wrapping(-1, -1), Some(&'f'));
+        assert_eq!(grid.get_clamped(5, -3), Some(&'b'));
+        assert_eq!(grid.get_clamped(-1, 7), Some(&'e'));
+
+        let empty: Grid<char> = Grid::from_fn(0, 3, |_, _| '.');
+        assert_eq!(empty.get_wrapping(1, 1), None);
+        assert_eq!(empty.get_clamped(1, 1), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8(0, 1).count(), 5);
+        assert_eq!(
+            grid.neighbors8(1, 1).map(|p| grid[p]).collect::<String>(),
+            "bfeca"
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::parse("abc\ndef\nghi", Some).unwrap();
+
+        assert_eq!(
+            grid.ray(0, 0, (1, 1)).map(|p| grid[p]).collect::<String>(),
+            "ei"
+        );
+        assert_eq!(
+            grid.ray(2, 1, (-1, 0)).map(|p| grid[p]).collect::<String>(),
+            "ed"
+        );
+        assert_eq!(grid.ray(0, 0, (0, -1)).count(), 0);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = sample();
+
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe\n");
+        assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = sample().map(|&c| c == 'c' || c == 'f');
+
+        assert_eq!(grid.count(|&b| b), 2);
+        assert_eq!(grid.render(|&b| if b { '#' } else { '.' }), "..\n#.\n.#\n");
+    }
+
+    #[test]
+    fn test_hex_tile_walk() {
+        use HexDirection::*;
+
+        assert_eq!(
+            HexTile::walk(&[East, NorthWest, SouthWest]),
+            HexTile::new(0, 0)
+        );
+        assert_eq!(
+            HexTile::walk(&[NorthWest, West, SouthWest, East, East]),
+            HexTile::new(0, 0)
+        );
+        assert_eq!(HexTile::walk(&[East, SouthEast, West]), HexTile::new(1, -1));
+        assert_eq!(HexTile::new(0, 0).neighbors().count(), 6);
+    }
+}
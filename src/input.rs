@@ -0,0 +1,202 @@
+use crate::error::Error;
+use crate::fetch::{Cache, Fetcher};
+use crate::warn;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory searched for puzzle inputs when neither `--inputs` nor `AOC_INPUTS` is given.
+pub const DEFAULT_INPUTS_DIR: &str = "src";
+
+/// Environment variable that overrides the default inputs directory.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` means standard input, anything else is a path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+/// Resolves the input of a day, laid out as `<dir>/yYYYY/chNN/input.txt`, unless it is in the
+/// cache.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+    cache: Option<Cache>,
+    fetcher: Option<Fetcher>,
+}
+
+impl Inputs {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Inputs {
+        Inputs {
+            dir: dir.into(),
+            cache: None,
+            fetcher: None,
+        }
+    }
+
+    /// Looks for inputs in `cache` first, downloading the missing ones with `fetcher` if there
+    /// is one.
+    pub fn with_cache(mut self, cache: Cache, fetcher: Option<Fetcher>) -> Inputs {
+        self.cache = Some(cache);
+        self.fetcher = fetcher;
+        self
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(format!("y{}", year))
+            .join(format!("ch{:02}", day))
+            .join("input.txt")
+    }
+
+    /// The file [`Inputs::read`] reads without an explicit source: the cached input if it was
+    /// downloaded, the day's file in the inputs directory otherwise.
+    pub fn file(&self, year: u16, day: u8) -> PathBuf {
+        match &self.cache {
+            Some(cache) if cache.path(year, day).is_file() => cache.path(year, day),
+            _ => self.path(year, day),
+        }
+    }
+
+    /// Reads the explicit `source` if there is one. Otherwise reads the cached input, downloading
+    /// it if it is missing, and falls back to the day's file in the inputs directory. A failed
+    /// download is kept as a warning, see [`crate::log::take_warnings`].
+    pub fn read(&self, year: u16, day: u8, source: Option<&InputSource>) -> Result<String, Error> {
+        match source {
+            Some(InputSource::Stdin) => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|e| Error::Input(format!("could not read input from stdin: {}", e)))?;
+                Ok(contents)
+            }
+            Some(InputSource::File(path)) => read_file(path),
+            None => match self.cached(year, day)? {
+                Some(contents) => Ok(contents),
+                None => read_file(&self.path(year, day)),
+            },
+        }
+    }
+
+    fn cached(&self, year: u16, day: u8) -> Result<Option<String>, Error> {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return Ok(None),
+        };
+        if let Some(contents) = cache.read(year, day)? {
+            return Ok(Some(contents));
+        }
+        let fetcher = match &self.fetcher {
+            Some(fetcher) => fetcher,
+            None => return Ok(None),
+        };
+        match fetcher.fetch(year, day) {
+            Ok(contents) => {
+                cache.store(year, day, &contents)?;
+                Ok(Some(contents))
+            }
+            Err(e) => {
+                warn!("{}, using {}", e, self.path(year, day).display());
+                Ok(None)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|e| {
+        Error::Input(format!(
+            "could not read input file {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("foo.txt"),
+            InputSource::File(PathBuf::from("foo.txt"))
+        );
+    }
+
+    #[test]
+    fn test_inputs_path() {
+        let inputs = Inputs::new("inputs");
+
+        assert_eq!(
+            inputs.path(2020, 3),
+            PathBuf::from("inputs/y2020/ch03/input.txt")
+        );
+        assert_eq!(
+            inputs.path(2021, 25),
+            PathBuf::from("inputs/y2021/ch25/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_inputs_read() {
+        let inputs = Inputs::new(DEFAULT_INPUTS_DIR);
+
+        assert_eq!(
+            inputs.read(2020, 15, None).unwrap().trim(),
+            "0,13,1,16,6,17"
+        );
+        assert!(inputs
+            .read(
+                2020,
+                15,
+                Some(&InputSource::File(PathBuf::from("missing.txt")))
+            )
+            .is_err());
+        assert!(Inputs::new("missing").read(2020, 1, None).is_err());
+    }
+
+    #[test]
+    fn test_inputs_read_cache_first() {
+        let dir =
+            std::env::temp_dir().join(format!("code-advent-2020-inputs-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        cache.store(2020, 15, "0,3,6\n").unwrap();
+        // nothing listens there, the download fails
+        let fetcher = Fetcher::new("http://127.0.0.1:9", "token");
+        let inputs = Inputs::new(DEFAULT_INPUTS_DIR).with_cache(cache, Some(fetcher));
+
+        assert_eq!(inputs.read(2020, 15, None).unwrap(), "0,3,6\n");
+        assert!(crate::log::take_warnings().is_empty());
+        assert_eq!(inputs.file(2020, 15), dir.join("2020").join("day15.txt"));
+        assert_eq!(inputs.file(2020, 13), inputs.path(2020, 13));
+        assert_eq!(
+            inputs.read(2020, 13, None).unwrap(),
+            read_file(&inputs.path(2020, 13)).unwrap()
+        );
+        let warnings = crate::log::take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].ends_with(&format!(", using {}", inputs.path(2020, 13).display())));
+        assert_eq!(
+            inputs
+                .read(2020, 15, Some(&InputSource::File(inputs.path(2020, 15))))
+                .unwrap()
+                .trim(),
+            "0,13,1,16,6,17"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+//! Just enough JSON to write machine-readable reports: flat objects, one per line.
+
+use crate::solution::Answer;
+use std::fmt;
+use std::fmt::Write;
+
+/// `s` as a JSON string literal.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                write!(quoted, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A JSON object whose fields are written in the order they were added.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Object {
+        Object::default()
+    }
+
+    fn field(mut self, key: &str, value: String) -> Object {
+        self.fields.push((quote(key), value));
+        self
+    }
+
+    pub fn string(self, key: &str, value: &str) -> Object {
+        self.field(key, quote(value))
+    }
+
+    pub fn number<N: Into<i128>>(self, key: &str, value: N) -> Object {
+        self.field(key, value.into().to_string())
+    }
+
+    pub fn null(self, key: &str) -> Object {
+        self.field(key, String::from("null"))
+    }
+
+    /// Numeric answers are written as numbers, the others as strings.
+    pub fn answer(self, key: &str, answer: &Answer) -> Object {
+        match answer {
+            Answer::Number(n) => self.number(key, *n),
+            Answer::Text(s) => self.string(key, s),
+        }
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (idx, (key, value)) in self.fields.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", key, value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("abc"), "\"abc\"");
+        assert_eq!(quote("a \"b\"\\c\n"), "\"a \\\"b\\\"\\\\c\\n\"");
+        assert_eq!(quote("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_object() {
+        let object = Object::new()
+            .number("day", 3u8)
+            .null("part")
+            .answer("answer", &Answer::from("a,b"))
+            .answer("count", &Answer::from(-2));
+
+        assert_eq!(
+            object.to_string(),
+            "{\"day\":3,\"part\":null,\"answer\":\"a,b\",\"count\":-2}"
+        );
+        assert_eq!(Object::new().to_string(), "{}");
+    }
+}
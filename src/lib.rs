@@ -0,0 +1,41 @@
+//! Solutions for Advent of Code, one module per year and one per day in it, plus the pieces they
+//! share.
+//!
+//! Every day exposes a `Day` implementing [`solution::Solution`]; each year lists them in its
+//! registry, and [`solution::solutions`] lists the days of a year. Adding a year takes a module
+//! `yYYYY` with its days and their registry, and an entry in [`solution`]. Reusable building
+//! blocks:
+//!
+//! - [`utils`]: splitting and parsing puzzle input, reporting [`error::Error`]s
+//! - [`parse`]: small parsers of numbers, words, lists and fields, combined into line parsers
+//! - [`grid`]: rectangular maps with neighborhoods, rays and rotations, and hexagonal tiles
+//! - [`automaton`]: cellular automata on grids, lattices and hexagonal grids
+//! - [`generate`]: seeded random puzzle inputs of any size, solvable by construction
+//! - [`y2020::ch08`]: the handheld console instruction set and interpreter
+//! - [`y2020::ch13`]: gcd, modular inverse and the chinese remainder theorem
+//! - [`y2020::ch18`]: arithmetic expressions with configurable operator precedence
+
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod check;
+pub mod checkpoint;
+pub mod differential;
+pub mod error;
+pub mod export;
+pub mod fetch;
+pub mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod memory;
+pub mod parse;
+pub mod progress;
+pub mod runner;
+pub mod solution;
+pub mod utils;
+pub mod viewer;
+pub mod watch;
+pub mod y2020;
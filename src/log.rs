@@ -0,0 +1,86 @@
+//! Diagnostic output of the solvers, kept apart from the answers.
+//!
+//! Messages go to stderr, and only if the verbosity set by the runner is high enough: the
+//! [`debug!`](crate::debug) level is for a few lines per day, the [`trace!`](crate::trace) level
+//! for dumps of intermediate state.
+//!
+//! Warnings, about something that went wrong without stopping a solver, are not printed but
+//! kept by [`warn!`](crate::warn) until whoever ran the solver takes them, to report them
+//! along with its outcome.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+pub const QUIET: u8 = 0;
+pub const DEBUG: u8 = 1;
+pub const TRACE: u8 = 2;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(QUIET);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn enabled(level: u8) -> bool {
+    verbosity() >= level
+}
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Keeps `message` until the warnings of the current thread are taken.
+pub fn warn(message: String) {
+    WARNINGS.with(|warnings| warnings.borrow_mut().push(message));
+}
+
+/// The warnings kept on the current thread since they were last taken.
+pub fn take_warnings() -> Vec<String> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
+/// Prints a diagnostic line to stderr if the verbosity is at least [`DEBUG`].
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::DEBUG) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints a diagnostic line to stderr if the verbosity is at least [`TRACE`].
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::TRACE) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Keeps a warning for whoever runs the solver, see [`take_warnings`].
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        $crate::log::warn(format!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warnings() {
+        assert!(take_warnings().is_empty());
+        crate::warn!("no checkpoints: {}", 42);
+        warn(String::from("again"));
+        assert_eq!(take_warnings(), vec!["no checkpoints: 42", "again"]);
+        assert!(take_warnings().is_empty());
+    }
+}
@@ -1,29 +1,857 @@
-mod ch01;
-mod ch02;
-mod ch03;
-mod ch04;
-mod ch05;
-mod ch06;
-mod ch07;
-mod ch08;
-mod ch09;
-mod ch10;
-mod ch11;
-mod ch12;
-mod ch13;
-mod ch14;
-mod ch15;
-mod ch16;
-mod ch17;
-mod ch18;
-mod ch19;
-mod ch20;
-mod ch22;
-mod ch23;
-mod ch24;
-mod ch25;
-mod utils;
+mod cli;
+
+use cli::OutputFormat;
+use code_advent_2020::answers::{self, AnswerStore};
+use code_advent_2020::error::Error;
+use code_advent_2020::fetch::{self, Cache, Fetcher};
+use code_advent_2020::input::{self, InputSource, Inputs};
+use code_advent_2020::json;
+use code_advent_2020::solution::Answer;
+use code_advent_2020::{
+    bench, check, checkpoint, differential, export, fixtures, generate, log, memory, progress,
+    runner, solution, viewer, watch, y2020,
+};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, process, thread};
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+/// Inputs in `dir` or `$AOC_INPUTS` if given, otherwise the downloaded ones before those in
+/// the default directory.
+fn inputs(dir: Option<PathBuf>) -> Inputs {
+    if let Some(dir) = dir.or_else(|| env::var_os(input::INPUTS_DIR_VAR).map(PathBuf::from)) {
+        return Inputs::new(dir);
+    }
+    let inputs = Inputs::new(input::DEFAULT_INPUTS_DIR);
+    match Cache::user() {
+        Some(cache) => inputs.with_cache(cache, Fetcher::from_env()),
+        None => inputs,
+    }
+}
+
+/// `day` of `year`, or every day of `year` if `day` is `None`. Without a year, a single day is
+/// one of [`solution::DEFAULT_YEAR`] and all days are those of every year.
+fn puzzles(year: Option<u16>, day: Option<u8>) -> Result<Vec<(u16, u8)>, Vec<Error>> {
+    match (year, day) {
+        (year, Some(day)) => Ok(vec![(year.unwrap_or(solution::DEFAULT_YEAR), day)]),
+        (Some(year), None) => {
+            let days: Vec<(u16, u8)> = solution::solutions(year)
+                .map(|(day, _)| (year, day))
+                .collect();
+            if days.is_empty() {
+                return Err(vec![Error::UnknownYear(year)]);
+            }
+            Ok(days)
+        }
+        (None, None) => Ok(solution::puzzles().collect()),
+    }
+}
+
+/// Reads an input as [`Inputs::read`] does, printing the warnings about it to stderr.
+fn read_input(
+    inputs: &Inputs,
+    year: u16,
+    day: u8,
+    source: Option<&InputSource>,
+) -> Result<String, Error> {
+    let input = inputs.read(year, day, source);
+    print_warnings(log::take_warnings());
+    input
+}
+
+/// Prints `warnings` to stderr.
+fn print_warnings<I: IntoIterator<Item = String>>(warnings: I) {
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+}
+
+/// JSON record of a day or part that could not be solved.
+fn json_error(year: u16, day: u8, part: Option<u8>, error: &Error) -> json::Object {
+    let record = json::Object::new().number("year", year).number("day", day);
+    let record = match part {
+        Some(part) => record.number("part", part),
+        None => record.null("part"),
+    };
+    record
+        .string("status", "error")
+        .string("error", &error.to_string())
+}
+
+fn run(
+    inputs: &Inputs,
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    input: Option<&InputSource>,
+    format: OutputFormat,
+) -> Result<(), Vec<Error>> {
+    let run = read_input(inputs, year, day, input).and_then(|contents| {
+        let parts: Vec<u8> = match part {
+            Some(part) => vec![part],
+            None => cli::PARTS.collect(),
+        };
+        runner::run_day(year, day, &contents, &parts)
+    });
+    let run = match run {
+        Ok(run) => run,
+        Err(e) => {
+            if format == OutputFormat::Json {
+                println!("{}", json_error(year, day, None, &e));
+            }
+            return Err(vec![e]);
+        }
+    };
+
+    let mut errors = Vec::new();
+    for part_run in run.parts {
+        print_part_warnings(day, &part_run);
+        match (&part_run.answer, format) {
+            (Ok(answer), OutputFormat::Text) => {
+                println!(
+                    "day {} part {}: {} ({} heap)",
+                    day,
+                    part_run.part,
+                    answer,
+                    format_heap(part_run.peak_heap)
+                )
+            }
+            (Ok(answer), OutputFormat::Json) => println!(
+                "{}",
+                with_usage(
+                    part_json(year, day, part_run.part, "ok", answer),
+                    run.parse_peak_heap,
+                    &part_run
+                )
+            ),
+            // only complain about a missing part if it was explicitly requested
+            (Err(Error::MissingPart { .. }), _) if part.is_none() => {}
+            (Err(e), _) => {
+                if format == OutputFormat::Json {
+                    println!("{}", json_error(year, day, Some(part_run.part), e));
+                }
+                errors.push(e.clone());
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn run_all(inputs: &Inputs, year: Option<u16>, format: OutputFormat) -> Result<(), Vec<Error>> {
+    let errors: Vec<Error> = puzzles(year, None)?
+        .into_iter()
+        .filter_map(|(year, day)| run(inputs, year, day, None, None, format).err())
+        .flatten()
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn format_heap(peak_heap: Option<usize>) -> String {
+    peak_heap.map_or_else(|| String::from("-"), memory::format_bytes)
+}
+
+/// Prints to stderr what the solution warned about while solving `part_run`.
+fn print_part_warnings(day: u8, part_run: &runner::PartRun) {
+    print_warnings(
+        part_run
+            .warnings
+            .iter()
+            .map(|warning| format!("day {} part {}: {}", day, part_run.part, warning)),
+    );
+}
+
+/// Row of `part_run` in the tables of `run --all --jobs` and `verify`.
+fn print_part_row(year: u16, day: u8, part_run: &runner::PartRun, status: &str, answer: &str) {
+    println!(
+        "{:>4} {:>3} {:>4} {:<7} {:>12} {:>9}  {}",
+        year,
+        day,
+        part_run.part,
+        status,
+        format_duration(part_run.duration),
+        format_heap(part_run.peak_heap),
+        answer
+    );
+}
+
+/// JSON record of a part that gave `answer`, `status` telling what became of it. The caller
+/// adds its own fields, then the cost of the part with [`with_usage`].
+fn part_json(year: u16, day: u8, part: u8, status: &str, answer: &Answer) -> json::Object {
+    json::Object::new()
+        .number("year", year)
+        .number("day", day)
+        .number("part", part)
+        .string("status", status)
+        .answer("answer", answer)
+}
+
+/// `record` of `part_run`, with the time it took and the peak heap of parsing the input and of
+/// solving the part.
+fn with_usage(
+    record: json::Object,
+    parse_peak_heap: Option<usize>,
+    part_run: &runner::PartRun,
+) -> json::Object {
+    let bytes = |record: json::Object, key: &str, peak_heap: Option<usize>| match peak_heap {
+        Some(bytes) => record.number(key, bytes as u64),
+        None => record.null(key),
+    };
+    let record = record.number("duration_ns", part_run.duration.as_nanos() as i128);
+    let record = bytes(record, "parse_peak_heap_bytes", parse_peak_heap);
+    bytes(record, "peak_heap_bytes", part_run.peak_heap)
+}
+
+/// Runs every day, `jobs` of them at a time, then prints all the answers in a single table.
+///
+/// In JSON mode only the results go to stdout, the summary is printed to stderr.
+fn run_parallel(
+    inputs: &Inputs,
+    year: Option<u16>,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<(), Vec<Error>> {
+    let start = Instant::now();
+    let mut days = Vec::new();
+    let mut unread = Vec::new();
+    for (year, day) in puzzles(year, None)? {
+        match read_input(inputs, year, day, None) {
+            Ok(input) => days.push((year, day, input)),
+            Err(e) => unread.push(((year, day), e)),
+        }
+    }
+    let mut runs: Vec<((u16, u8), Result<runner::DayRun, Error>)> = days
+        .iter()
+        .map(|&(year, day, _)| (year, day))
+        .zip(runner::run_days(&days, &[1, 2], jobs))
+        .chain(unread.into_iter().map(|(puzzle, e)| (puzzle, Err(e))))
+        .collect();
+    runs.sort_by_key(|&(puzzle, _)| puzzle);
+    let wall_time = start.elapsed();
+
+    let mut errors = Vec::new();
+    let (mut solved, mut solving_time) = (0, Duration::default());
+    let mut largest_heap: Option<(usize, u16, u8, u8)> = None;
+    if format == OutputFormat::Text {
+        println!("year day part status  {:>12} {:>9}  answer", "time", "heap");
+    }
+    for ((year, day), run) in runs {
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => {
+                match format {
+                    OutputFormat::Text => {
+                        println!(
+                            "{:>4} {:>3}    - error   {:>12} {:>9}  -",
+                            year, day, "-", "-"
+                        )
+                    }
+                    OutputFormat::Json => println!("{}", json_error(year, day, None, &e)),
+                }
+                errors.push(e.with_day(day));
+                continue;
+            }
+        };
+        solving_time += run.parse_duration;
+
+        for part_run in run.parts {
+            print_part_warnings(day, &part_run);
+            let part = part_run.part;
+            solving_time += part_run.duration;
+
+            match &part_run.answer {
+                Ok(answer) => {
+                    solved += 1;
+                    if let Some(bytes) = part_run.peak_heap {
+                        if largest_heap.is_none_or(|(largest, ..)| bytes > largest) {
+                            largest_heap = Some((bytes, year, day, part));
+                        }
+                    }
+                    match format {
+                        OutputFormat::Text => {
+                            print_part_row(year, day, &part_run, "ok", &answer.to_string())
+                        }
+                        OutputFormat::Json => println!(
+                            "{}",
+                            with_usage(
+                                part_json(year, day, part, "ok", answer),
+                                run.parse_peak_heap,
+                                &part_run
+                            )
+                        ),
+                    }
+                }
+                Err(Error::MissingPart { .. }) => {}
+                Err(e) => {
+                    match format {
+                        OutputFormat::Text => print_part_row(year, day, &part_run, "error", "-"),
+                        OutputFormat::Json => println!("{}", json_error(year, day, Some(part), e)),
+                    }
+                    errors.push(e.clone());
+                }
+            }
+        }
+    }
+
+    let mut summary = format!(
+        "{} solved, {} errors in {} ({} of solving on {} job{})",
+        solved,
+        errors.len(),
+        format_duration(wall_time),
+        format_duration(solving_time),
+        jobs,
+        if jobs == 1 { "" } else { "s" }
+    );
+    if let Some((bytes, year, day, part)) = largest_heap {
+        summary += &format!(
+            ", most heap: {} by {} day {} part {}",
+            memory::format_bytes(bytes),
+            year,
+            day,
+            part
+        );
+    }
+    match format {
+        OutputFormat::Text => println!("\n{}", summary),
+        OutputFormat::Json => eprintln!("{}", summary),
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Runs every day against its input and compares the answers with the ones in the store.
+///
+/// Errors are only marked in the report, they are returned to be printed in full afterwards. In
+/// JSON mode only the results go to stdout, the summary is printed to stderr.
+fn verify(
+    inputs: &Inputs,
+    year: Option<u16>,
+    answers_path: &Path,
+    record: bool,
+    format: OutputFormat,
+) -> Result<(), Vec<Error>> {
+    let puzzles = puzzles(year, None)?;
+    let mut store = AnswerStore::load(answers_path).map_err(|e| vec![e])?;
+
+    let mut errors = Vec::new();
+    let (mut passed, mut failed, mut missing, mut recorded) = (0, 0, 0, 0);
+    let start = Instant::now();
+
+    if format == OutputFormat::Text {
+        println!("year day part status  {:>12} {:>9}  answer", "time", "heap");
+    }
+    for (year, day) in puzzles {
+        let run = read_input(inputs, year, day, None).and_then(|input| {
+            Ok((
+                answers::input_hash(&input),
+                runner::run_day(year, day, &input, &[1, 2])?,
+            ))
+        });
+        let (hash, run) = match run {
+            Ok(run) => run,
+            Err(e) => {
+                match format {
+                    OutputFormat::Text => {
+                        println!(
+                            "{:>4} {:>3}    - error   {:>12} {:>9}  -",
+                            year, day, "-", "-"
+                        )
+                    }
+                    OutputFormat::Json => println!("{}", json_error(year, day, None, &e)),
+                }
+                errors.push(e);
+                continue;
+            }
+        };
+
+        for part_run in run.parts {
+            print_part_warnings(day, &part_run);
+            let part = part_run.part;
+
+            let answer = match &part_run.answer {
+                Ok(answer) => answer,
+                Err(Error::MissingPart { .. }) => continue,
+                Err(e) => {
+                    match format {
+                        OutputFormat::Text => print_part_row(year, day, &part_run, "error", "-"),
+                        OutputFormat::Json => println!("{}", json_error(year, day, Some(part), e)),
+                    }
+                    errors.push(e.clone());
+                    continue;
+                }
+            };
+            let text = answer.to_string();
+
+            let expected = store.get(year, day, part, hash).map(String::from);
+            let status = match &expected {
+                Some(expected) if *expected == text => {
+                    passed += 1;
+                    "pass"
+                }
+                Some(_) => {
+                    failed += 1;
+                    "fail"
+                }
+                None => {
+                    missing += 1;
+                    "missing"
+                }
+            };
+
+            match format {
+                OutputFormat::Text => match (status, &expected) {
+                    ("fail", Some(expected)) => print_part_row(
+                        year,
+                        day,
+                        &part_run,
+                        "FAIL",
+                        &format!("{} (expected {})", text, expected),
+                    ),
+                    _ => print_part_row(year, day, &part_run, status, &text),
+                },
+                OutputFormat::Json => {
+                    let result = part_json(year, day, part, status, answer);
+                    let result = match &expected {
+                        // the store only keeps text, give it back the type of the answer
+                        Some(expected) => match expected.parse() {
+                            Ok(n) => result.answer("expected", &Answer::Number(n)),
+                            Err(_) => result.string("expected", expected),
+                        },
+                        None => result.null("expected"),
+                    };
+                    println!("{}", with_usage(result, run.parse_peak_heap, &part_run));
+                }
+            }
+
+            if expected.is_none() && record {
+                store.insert(year, day, part, hash, text);
+                recorded += 1;
+            }
+        }
+    }
+
+    let summary = format!(
+        "{} passed, {} failed, {} missing, {} errors in {}",
+        passed,
+        failed,
+        missing,
+        errors.len(),
+        format_duration(start.elapsed())
+    );
+    match format {
+        OutputFormat::Text => println!("\n{}", summary),
+        OutputFormat::Json => eprintln!("{}", summary),
+    }
+
+    if recorded > 0 {
+        store.save(answers_path).map_err(|e| vec![e])?;
+        let message = format!(
+            "recorded {} answers in {}",
+            recorded,
+            answers_path.display()
+        );
+        match format {
+            OutputFormat::Text => println!("{}", message),
+            OutputFormat::Json => eprintln!("{}", message),
+        }
+    }
+
+    if failed > 0 || !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(())
+    }
+}
+
+fn bench(
+    inputs: &Inputs,
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: usize,
+    format: cli::BenchFormat,
+) -> Result<(), Vec<Error>> {
+    let mut errors = Vec::new();
+    let mut rows = Vec::new();
+    for (year, day) in puzzles(year, day)? {
+        match read_input(inputs, year, day, None)
+            .and_then(|input| bench::bench_day(year, day, &input, runs))
+        {
+            Ok(day_bench) => {
+                rows.push((
+                    year,
+                    day,
+                    String::from("parse"),
+                    day_bench.parse,
+                    day_bench.parse_peak_heap,
+                ));
+                for (part, stats, peak_heap) in day_bench.parts {
+                    rows.push((year, day, format!("part{}", part), stats, peak_heap));
+                }
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    match format {
+        cli::BenchFormat::Table => {
+            println!(
+                "year day stage  runs {:>12} {:>12} {:>12} {:>9}",
+                "min", "median", "max", "heap"
+            );
+            for (year, day, stage, stats, peak_heap) in &rows {
+                println!(
+                    "{:>4} {:>3} {:<6} {:>4} {:>12} {:>12} {:>12} {:>9}",
+                    year,
+                    day,
+                    stage,
+                    runs,
+                    format_duration(stats.min),
+                    format_duration(stats.median),
+                    format_duration(stats.max),
+                    format_heap(*peak_heap)
+                );
+            }
+        }
+        cli::BenchFormat::Tsv => {
+            println!("year\tday\tstage\truns\tmin_ns\tmedian_ns\tmax_ns\tpeak_heap_bytes");
+            for (year, day, stage, stats, peak_heap) in &rows {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    year,
+                    day,
+                    stage,
+                    runs,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos(),
+                    peak_heap.map(|bytes| bytes.to_string()).unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn differential(day: Option<u8>, seeds: u64) -> Result<(), Vec<Error>> {
+    if differential::pairs(day).next().is_none() {
+        return Err(vec![Error::Input(format!(
+            "day {} is solved only once",
+            day.unwrap_or_default()
+        ))]);
+    }
+
+    let mut errors = Vec::new();
+    for pair in differential::pairs(day) {
+        let disagreements = differential::check(Some(pair.day), 0..seeds);
+        println!(
+            "day {}: {} and {} agree on {} of {} inputs",
+            pair.day,
+            pair.naive,
+            pair.optimized,
+            seeds - disagreements.len() as u64,
+            seeds
+        );
+        errors.extend(
+            disagreements
+                .iter()
+                .map(|disagreement| Error::Input(disagreement.to_string())),
+        );
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Downloads the inputs of `day`, or of every day, that are not in the cache yet, or all of
+/// them if `force` is set.
+fn fetch(year: Option<u16>, day: Option<u8>, force: bool) -> Result<(), Vec<Error>> {
+    let cache = Cache::user().ok_or_else(|| {
+        vec![Error::Input(format!(
+            "no cache directory, set {}",
+            fetch::CACHE_DIR_VAR
+        ))]
+    })?;
+    let fetcher = Fetcher::from_env().ok_or_else(|| {
+        vec![Error::Input(format!(
+            "no session, set {} to the session cookie of the puzzle site",
+            fetch::SESSION_VAR
+        ))]
+    })?;
+
+    let mut errors = Vec::new();
+    for (year, day) in puzzles(year, day)? {
+        let cached = cache.read(year, day).map(|contents| contents.is_some());
+        let result = match cached {
+            Ok(true) if !force => {
+                let path = cache.path(year, day);
+                println!("{} day {}: already in {}", year, day, path.display());
+                continue;
+            }
+            Ok(_) => fetcher
+                .fetch(year, day)
+                .and_then(|contents| cache.store(year, day, &contents)),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(path) => println!("{} day {}: downloaded to {}", year, day, path.display()),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Reruns `day` on its examples and on the input in `input`, or the one it reads by default,
+/// every time the input changes, printing the answers that changed. Runs until interrupted.
+fn watch(
+    inputs: &Inputs,
+    year: u16,
+    day: u8,
+    input: Option<PathBuf>,
+    interval: Duration,
+) -> Result<(), Vec<Error>> {
+    solution::find(year, day).map_err(|e| vec![e])?;
+    let examples = match fixtures::examples(year, day) {
+        Ok(examples) => examples,
+        // days without worked examples are only run on their input
+        Err(Error::UnknownYear(_)) | Err(Error::UnknownDay(_)) => Vec::new(),
+        Err(e) => return Err(vec![e]),
+    };
+    let path = input.unwrap_or_else(|| inputs.file(year, day));
+
+    println!(
+        "watching {} every {}ms, with {} example{}",
+        path.display(),
+        interval.as_millis(),
+        examples.len(),
+        if examples.len() == 1 { "" } else { "s" }
+    );
+    let mut poller = watch::Poller::new(&path);
+    let mut last: Option<watch::Snapshot> = None;
+    for run in 1.. {
+        let contents = loop {
+            if let Some(contents) = poller.poll() {
+                break contents;
+            }
+            thread::sleep(interval);
+        };
+
+        match &contents {
+            Ok(_) if run == 1 => println!("\n== run 1"),
+            Ok(_) => println!("\n== run {}, input changed", run),
+            Err(message) => println!("\n== run {}, {}", run, message),
+        }
+        let snapshot = watch::Snapshot::take(year, day, &examples, contents.as_deref().ok());
+        let changes = match &last {
+            Some(last) => last.changes(&snapshot),
+            None => snapshot.answers(),
+        };
+        if changes.is_empty() {
+            println!("no answer changed");
+        }
+        for change in changes {
+            println!("{}", change);
+        }
+        last = Some(snapshot);
+    }
+    Ok(())
+}
+
+/// Prints what the input of `day` is made of, and returns the problems found in it.
+fn check(
+    inputs: &Inputs,
+    year: u16,
+    day: u8,
+    input: Option<&InputSource>,
+) -> Result<(), Vec<Error>> {
+    let report = read_input(inputs, year, day, input)
+        .and_then(|input| check::check(year, day, &input))
+        .map_err(|e| vec![e.with_day(day)])?;
+
+    let plural = |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+    println!(
+        "day {}: {}, {}",
+        day,
+        plural(report.lines, "line"),
+        plural(report.problems.len(), "problem")
+    );
+    match &report.summary {
+        Some(summary) if report.problems.is_empty() => print!("{}", summary),
+        Some(summary) => print!("without the lines with problems:\n{}", summary),
+        None => println!("nothing could be parsed"),
+    }
+
+    if report.problems.is_empty() {
+        Ok(())
+    } else {
+        Err(report.problems)
+    }
+}
 
 fn main() {
-    ch25::solve_part1();
+    let (verbosity, args) = cli::split_verbosity(env::args().skip(1).collect());
+    log::set_verbosity(verbosity);
+
+    let command = match cli::parse(args.into_iter()) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        cli::Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        cli::Command::Run {
+            year,
+            day,
+            part,
+            input,
+            inputs_dir,
+            format,
+            checkpoints,
+            progress,
+        } => {
+            checkpoint::configure(checkpoints);
+            progress::show_bars(progress);
+            let year = year.unwrap_or(solution::DEFAULT_YEAR);
+            run(&inputs(inputs_dir), year, day, part, input.as_ref(), format)
+        }
+        cli::Command::RunAll {
+            year,
+            inputs_dir,
+            format,
+            checkpoints,
+            progress,
+            jobs,
+        } => {
+            checkpoint::configure(checkpoints);
+            progress::show_bars(progress);
+            match jobs {
+                Some(jobs) => run_parallel(&inputs(inputs_dir), year, format, jobs),
+                None => run_all(&inputs(inputs_dir), year, format),
+            }
+        }
+        cli::Command::Verify {
+            year,
+            inputs_dir,
+            answers,
+            record,
+            format,
+        } => {
+            let answers = answers.unwrap_or_else(|| PathBuf::from(answers::DEFAULT_ANSWERS_FILE));
+            verify(&inputs(inputs_dir), year, &answers, record, format)
+        }
+        cli::Command::Bench {
+            year,
+            day,
+            runs,
+            format,
+            inputs_dir,
+        } => bench(&inputs(inputs_dir), year, day, runs, format),
+        cli::Command::Differential { day, seeds } => differential(day, seeds),
+        cli::Command::Export {
+            day,
+            part,
+            input,
+            inputs_dir,
+            format,
+            scale,
+            out,
+        } => read_input(&inputs(inputs_dir), y2020::YEAR, day, input.as_ref())
+            .and_then(|input| export::frames(day, &input, part))
+            .and_then(|frames| {
+                let name = format!("day{:02}-part{}", day, part);
+                export::write_frames(&frames, &out, &name, format, scale)
+            })
+            .map(|paths| {
+                for path in paths {
+                    println!("{}", path.display());
+                }
+            })
+            .map_err(|e| vec![e.with_day(day)]),
+        cli::Command::View {
+            day,
+            part,
+            input,
+            inputs_dir,
+            delay,
+        } => read_input(&inputs(inputs_dir), y2020::YEAR, day, input.as_ref())
+            .and_then(|input| export::frames(day, &input, part))
+            .and_then(|frames| {
+                let title = format!("day {} part {}", day, part);
+                viewer::view(&frames, &title, delay)
+            })
+            .map_err(|e| vec![e.with_day(day)]),
+        cli::Command::Fetch { year, day, force } => fetch(year, day, force),
+        cli::Command::Watch {
+            year,
+            day,
+            input,
+            inputs_dir,
+            interval,
+        } => {
+            let year = year.unwrap_or(solution::DEFAULT_YEAR);
+            watch(&inputs(inputs_dir), year, day, input, interval)
+        }
+        cli::Command::Check {
+            year,
+            day,
+            input,
+            inputs_dir,
+        } => {
+            let year = year.unwrap_or(solution::DEFAULT_YEAR);
+            check(&inputs(inputs_dir), year, day, input.as_ref())
+        }
+        cli::Command::Generate { day, seed, size } => {
+            let size = size.unwrap_or_else(|| generate::default_size(day).unwrap());
+            let input = generate::generate(day, seed, size).unwrap();
+            print!("{}", input);
+            if !input.ends_with('\n') {
+                println!();
+            }
+            Ok(())
+        }
+    };
+
+    if let Err(errors) = result {
+        for error in errors {
+            eprintln!("error: {}", error);
+        }
+        process::exit(1);
+    }
 }
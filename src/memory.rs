@@ -0,0 +1,151 @@
+//! Heap usage of the solvers, counted by a global allocator.
+//!
+//! [`Counting`] hands every allocation to the system allocator and keeps, per thread, how many
+//! bytes are allocated and the most that were at any time. [`measure`] reads that peak around
+//! a piece of code; it only knows something once a binary installs the allocator:
+//!
+//! ```
+//! use code_advent_2020::memory;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: memory::Counting = memory::Counting;
+//!
+//! let (_, peak_heap) = memory::measure(|| vec![0u64; 1000]);
+//! assert!(peak_heap >= Some(8000));
+//! ```
+//!
+//! Counting per thread keeps days solved side by side out of each other's numbers, but memory
+//! freed by another thread than the one that allocated it is counted on the wrong thread.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Global allocator counting the heap allocated by each thread.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Counting;
+
+/// Whether an allocation went through [`Counting`], which means it is the global allocator.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn record(delta: isize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    // the counters are gone while the thread is being torn down, its last frees are not counted
+    let _ = CURRENT.try_with(|current| {
+        let now = current.get() + delta;
+        current.set(now);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f` and returns what it returns, along with the most bytes of heap the current thread
+/// had allocated while it ran, on top of what was allocated before it started.
+///
+/// The peak is `None` unless [`Counting`] is the global allocator.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<usize>) {
+    let start = CURRENT.with(Cell::get);
+    let outer_peak = PEAK.with(|peak| peak.replace(start));
+
+    let value = f();
+
+    // a measure around this one still sees the peak reached inside it
+    let peak = PEAK.with(|peak| peak.replace(outer_peak.max(peak.get())));
+    let peak = if INSTALLED.load(Ordering::Relaxed) {
+        Some((peak - start).max(0) as usize)
+    } else {
+        None
+    };
+    (value, peak)
+}
+
+/// `bytes` in the largest binary unit that keeps the number above 1, like `1.5MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (len, peak) = measure(|| vec![0u8; 1 << 20].len());
+        assert_eq!(len, 1 << 20);
+        let peak = peak.unwrap();
+        assert!((1 << 20..2 << 20).contains(&peak), "peak of {}", peak);
+
+        let kept = vec![0u64; 1000];
+        assert_eq!(measure(|| kept.len()).1, Some(0));
+
+        // the peak inside a nested measure is part of the outer one too
+        let ((_, inner), outer) = measure(|| {
+            let _ = vec![0u8; 4096];
+            measure(|| (0..1000u32).collect::<Vec<_>>())
+        });
+        let (inner, outer) = (inner.unwrap(), outer.unwrap());
+        assert!((4000..12000).contains(&inner), "inner peak of {}", inner);
+        assert!(outer >= inner.max(4096), "outer peak of {}", outer);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0B");
+        assert_eq!(format_bytes(1023), "1023B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(8 << 20), "8.0MiB");
+        assert_eq!(format_bytes(3 << 30), "3.0GiB");
+    }
+}
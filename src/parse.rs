@@ -0,0 +1,318 @@
+//! Small parsers of the parts of a line, combined into the parser of a whole line.
+//!
+//! A [`Cursor`] walks over a line: each parser consumes what it recognizes from the front of
+//! what is left, or fails with an [`Error`] pointing at the column where it stopped. Parsers
+//! taking other parsers, like [`Cursor::separated`] or [`Cursor::field`], build lists and
+//! labeled values out of them, and [`line()`] makes sure nothing is left over:
+//!
+//! ```
+//! use code_advent_2020::parse::{self, Cursor};
+//!
+//! let rule = parse::line("row: 6-11 or 33-44", |cursor| {
+//!     cursor.field(": ", |cursor| {
+//!         cursor.separated(" or ", |cursor| {
+//!             let start: u32 = cursor.integer()?;
+//!             cursor.tag("-")?;
+//!             Ok((start, cursor.integer::<u32>()?))
+//!         })
+//!     })
+//! });
+//! assert_eq!(rule, Ok(("row", vec![(6, 11), (33, 44)])));
+//! ```
+
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Position in a line being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: &'a str) -> Cursor<'a> {
+        Cursor { line, rest: line }
+    }
+
+    /// What is left to parse.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Error about `text`, a part of the line.
+    pub fn error<S: Into<String>>(&self, text: &str, message: S) -> Error {
+        Error::parse(self.line, text, message)
+    }
+
+    /// What is left up to the next whitespace, to point errors at.
+    fn token(&self) -> &'a str {
+        let end = self
+            .rest
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest.len());
+        &self.rest[..end]
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (consumed, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        consumed
+    }
+
+    /// Consumes `literal`.
+    pub fn tag(&mut self, literal: &str) -> Result<(), Error> {
+        if self.try_tag(literal) {
+            Ok(())
+        } else {
+            Err(self.error(self.token(), format!("expected '{}'", literal)))
+        }
+    }
+
+    /// Consumes `literal` if the rest starts with it.
+    pub fn try_tag(&mut self, literal: &str) -> bool {
+        let found = self.rest.starts_with(literal);
+        if found {
+            self.advance(literal.len());
+        }
+        found
+    }
+
+    /// Consumes the characters matching `predicate`, possibly none.
+    pub fn take_while<P: Fn(char) -> bool>(&mut self, predicate: P) -> &'a str {
+        let end = self.rest.find(|c| !predicate(c)).unwrap_or(self.rest.len());
+        self.advance(end)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Consumes a run of letters and digits.
+    pub fn word(&mut self) -> Result<&'a str, Error> {
+        let word = self.take_while(char::is_alphanumeric);
+        if word.is_empty() {
+            return Err(self.error(self.token(), "expected a word"));
+        }
+        Ok(word)
+    }
+
+    /// Consumes a number in decimal, with an optional sign.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, Error> {
+        self.number(true)
+    }
+
+    /// Consumes a number in decimal made of digits only, without a sign.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, Error> {
+        self.number(false)
+    }
+
+    fn number<T: FromStr>(&mut self, signed: bool) -> Result<T, Error> {
+        let token = self.token();
+        let sign = match self.rest.chars().next() {
+            Some('+') | Some('-') if signed => 1,
+            _ => 0,
+        };
+        let digits = self.rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error(token, "invalid number"));
+        }
+
+        let number = &self.rest[..sign + digits];
+        let value = number
+            .parse()
+            .map_err(|_| self.error(number, "invalid number"))?;
+        self.advance(number.len());
+        Ok(value)
+    }
+
+    /// Consumes the text up to `delimiter`, then the delimiter, and returns the text.
+    pub fn until(&mut self, delimiter: &str) -> Result<&'a str, Error> {
+        let end = self
+            .rest
+            .find(delimiter)
+            .ok_or_else(|| self.error(self.rest, format!("missing '{}'", delimiter)))?;
+        let text = self.advance(end);
+        self.advance(delimiter.len());
+        Ok(text)
+    }
+
+    /// Consumes a field like `name: value`: a label, not empty, up to `separator`, then the
+    /// value parsed by `value`.
+    pub fn field<T, F>(&mut self, separator: &str, value: F) -> Result<(&'a str, T), Error>
+    where
+        F: FnOnce(&mut Cursor<'a>) -> Result<T, Error>,
+    {
+        let label = self.until(separator)?;
+        if label.trim().is_empty() {
+            return Err(self.error(label, format!("missing label before '{}'", separator)));
+        }
+        Ok((label, value(self)?))
+    }
+
+    /// Consumes one or more items parsed by `item`, separated by `separator`.
+    ///
+    /// The list ends at the first separator not followed by an item: if `item` fails without
+    /// consuming anything there, the separator is left for what comes after the list. An item
+    /// failing halfway is an error.
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, Error>,
+    {
+        let mut items = vec![item(self)?];
+        loop {
+            let mut next = *self;
+            if !next.try_tag(separator) {
+                return Ok(items);
+            }
+            let start = next.rest;
+            match item(&mut next) {
+                Ok(value) => {
+                    items.push(value);
+                    *self = next;
+                }
+                Err(_) if next.rest == start => return Ok(items),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Consumes `open`, what `inner` parses, then `close`.
+    pub fn delimited<T, F>(&mut self, open: &str, close: &str, inner: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut Cursor<'a>) -> Result<T, Error>,
+    {
+        self.tag(open)?;
+        let value = inner(self)?;
+        self.tag(close)?;
+        Ok(value)
+    }
+
+    /// Succeeds if nothing but whitespace is left.
+    pub fn end(&self) -> Result<(), Error> {
+        let rest = self.rest.trim_end();
+        if rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(rest, "unexpected text"))
+        }
+    }
+}
+
+/// Parses the whole of `text` with `parser`, failing if anything but whitespace is left.
+pub fn line<'a, T, F>(text: &'a str, parser: F) -> Result<T, Error>
+where
+    F: FnOnce(&mut Cursor<'a>) -> Result<T, Error>,
+{
+    let mut cursor = Cursor::new(text);
+    let value = parser(&mut cursor)?;
+    cursor.end()?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_and_word() {
+        let mut cursor = Cursor::new("acc +3");
+
+        assert_eq!(cursor.word(), Ok("acc"));
+        assert!(!cursor.try_tag("-"));
+        assert_eq!(
+            cursor.tag(": ").unwrap_err().to_string(),
+            "line 1, column 4: expected ': '"
+        );
+        cursor.tag(" ").unwrap();
+        assert_eq!(
+            cursor.word().unwrap_err().to_string(),
+            "line 1, column 5: expected a word: '+3'"
+        );
+        assert_eq!(cursor.rest(), "+3");
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(line("-42", |c| c.integer::<i32>()), Ok(-42));
+        assert_eq!(line("+7 ", |c| c.integer::<u8>()), Ok(7));
+        assert_eq!(
+            line("1-x", |c| {
+                c.integer::<u32>()?;
+                c.tag("-")?;
+                c.integer::<u32>()
+            }),
+            Err(Error::parse("1-x", "x", "invalid number"))
+        );
+        assert_eq!(
+            line("300", |c| c.integer::<u8>()),
+            Err(Error::parse("300", "300", "invalid number"))
+        );
+        assert_eq!(
+            line("12x", |c| c.integer::<u8>()).unwrap_err().to_string(),
+            "line 1, column 3: unexpected text: 'x'"
+        );
+        assert!(line("", |c| c.integer::<u8>()).is_err());
+
+        assert_eq!(line("42", |c| c.unsigned::<i32>()), Ok(42));
+        assert_eq!(
+            line("-1-3", |c| c.unsigned::<i32>()),
+            Err(Error::parse("-1-3", "-1-3", "invalid number"))
+        );
+        assert!(line("+7", |c| c.unsigned::<u8>()).is_err());
+    }
+
+    #[test]
+    fn test_until_and_field() {
+        let mut cursor = Cursor::new("light red bags contain no other bags.");
+        assert_eq!(cursor.until(" bags contain "), Ok("light red"));
+        assert_eq!(cursor.rest(), "no other bags.");
+        assert_eq!(
+            cursor.until(", ").unwrap_err().to_string(),
+            "line 1, column 24: missing ', ': 'no other bags.'"
+        );
+
+        assert_eq!(
+            line("ecl:gry", |c| c.field(":", Cursor::word)),
+            Ok(("ecl", "gry"))
+        );
+        assert!(line(":gry", |c| c.field(":", Cursor::word)).is_err());
+    }
+
+    #[test]
+    fn test_separated() {
+        let numbers = |c: &mut Cursor| c.separated(",", |c| c.integer::<u32>());
+        assert_eq!(
+            line("7,13,x", numbers),
+            Err(Error::parse("7,13,x", ",x", "unexpected text"))
+        );
+        assert_eq!(line("7,13,19", numbers), Ok(vec![7, 13, 19]));
+
+        // the item after the last separator is not there, the separator is left over
+        let mut cursor = Cursor::new("sqjhc fvjkl (contains soy)");
+        assert_eq!(
+            cursor.separated(" ", Cursor::word),
+            Ok(vec!["sqjhc", "fvjkl"])
+        );
+        assert_eq!(
+            cursor.delimited(" (contains ", ")", |c| c.separated(", ", Cursor::word)),
+            Ok(vec!["soy"])
+        );
+        cursor.end().unwrap();
+
+        // an item failing halfway is an error
+        let ranges = line("1-3 or 5-x", |c| {
+            c.separated(" or ", |c| {
+                c.integer::<u32>()?;
+                c.tag("-")?;
+                c.integer::<u32>()
+            })
+        });
+        assert_eq!(
+            ranges,
+            Err(Error::parse("1-3 or 5-x", "x", "invalid number"))
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Progress of long-running solvers.
+//!
+//! Solvers that take many steps report how far along they are to a [`Progress`] given by their
+//! caller, which decides what to do with it: nothing ([`Silent`]), draw a bar on stderr
+//! ([`Bar`]), or hand the numbers to a closure ([`Callback`]). Puzzle parts run through
+//! [`crate::solution::Solution`] use [`reporter`], which draws bars once [`show_bars`] was
+//! enabled.
+
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Number of updates a solver sends over a run with a known number of steps.
+pub const UPDATES: usize = 100;
+
+const BAR_WIDTH: usize = 30;
+
+static SHOW_BARS: AtomicBool = AtomicBool::new(false);
+
+/// Whether [`reporter`] draws bars on stderr.
+pub fn show_bars(enabled: bool) {
+    SHOW_BARS.store(enabled, Ordering::Relaxed);
+}
+
+/// The reporter of the solver named `label`: a [`Bar`] if [`show_bars`] was enabled, [`Silent`]
+/// otherwise.
+pub fn reporter(label: &str) -> Box<dyn Progress> {
+    if SHOW_BARS.load(Ordering::Relaxed) {
+        Box::new(Bar::new(label))
+    } else {
+        Box::new(Silent)
+    }
+}
+
+/// Steps between two updates of a run of `total` steps, so that it sends about [`UPDATES`]
+/// of them. Never 0.
+pub fn stride(total: usize) -> usize {
+    (total / UPDATES).max(1)
+}
+
+/// Receives the progress of a solver.
+pub trait Progress {
+    /// `done` steps were taken out of `total`, or out of an unknown number if `None`.
+    fn update(&mut self, done: usize, total: Option<usize>);
+
+    /// The solver stopped, no more updates follow.
+    fn finish(&mut self) {}
+}
+
+/// Ignores all updates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Silent;
+
+impl Progress for Silent {
+    fn update(&mut self, _done: usize, _total: Option<usize>) {}
+}
+
+/// Passes every update to a closure.
+pub struct Callback<F>(pub F);
+
+impl<F> Progress for Callback<F>
+where
+    F: FnMut(usize, Option<usize>),
+{
+    fn update(&mut self, done: usize, total: Option<usize>) {
+        (self.0)(done, total)
+    }
+}
+
+/// Draws a bar on stderr, redrawing it in place on each update that changes it.
+#[derive(Debug)]
+pub struct Bar {
+    label: String,
+    drawn: Option<String>,
+}
+
+impl Bar {
+    pub fn new(label: &str) -> Bar {
+        Bar {
+            label: String::from(label),
+            drawn: None,
+        }
+    }
+
+    fn render(&self, done: usize, total: Option<usize>) -> String {
+        match total {
+            Some(total) => {
+                let fraction = if total == 0 {
+                    1.0
+                } else {
+                    done.min(total) as f64 / total as f64
+                };
+                let filled = (fraction * BAR_WIDTH as f64) as usize;
+                format!(
+                    "{} [{}{}] {:3}%",
+                    self.label,
+                    "#".repeat(filled),
+                    " ".repeat(BAR_WIDTH - filled),
+                    (fraction * 100.0) as usize
+                )
+            }
+            None => format!("{} [{} steps]", self.label, done),
+        }
+    }
+}
+
+impl Progress for Bar {
+    fn update(&mut self, done: usize, total: Option<usize>) {
+        let line = self.render(done, total);
+        if self.drawn.as_ref() != Some(&line) {
+            let mut stderr = io::stderr();
+            let _ = write!(stderr, "\r{}", line);
+            let _ = stderr.flush();
+            self.drawn = Some(line);
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.drawn.take().is_some() {
+            eprintln!();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stride() {
+        assert_eq!(stride(0), 1);
+        assert_eq!(stride(7), 1);
+        assert_eq!(stride(2020), 20);
+        assert_eq!(stride(30_000_000), 300_000);
+    }
+
+    #[test]
+    fn test_callback() {
+        let mut updates = Vec::new();
+        let mut progress = Callback(|done, total| updates.push((done, total)));
+        progress.update(1, Some(2));
+        progress.update(3, None);
+        progress.finish();
+
+        assert_eq!(updates, vec![(1, Some(2)), (3, None)]);
+    }
+
+    #[test]
+    fn test_bar_render() {
+        let bar = Bar::new("day 15");
+        assert_eq!(
+            bar.render(15, Some(30)),
+            format!("day 15 [{}{}]  50%", "#".repeat(15), " ".repeat(15))
+        );
+        assert_eq!(
+            bar.render(40, Some(30)),
+            format!("day 15 [{}] 100%", "#".repeat(30))
+        );
+        assert_eq!(
+            bar.render(0, Some(0)),
+            format!("day 15 [{}] 100%", "#".repeat(30))
+        );
+        assert_eq!(bar.render(12, None), "day 15 [12 steps]");
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::Error;
+use crate::log;
+use crate::memory;
+use crate::solution::{self, Answer};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Outcome of solving one part of a day.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    pub duration: Duration,
+    /// Most heap allocated while solving, on top of the parsed input. `None` unless the
+    /// allocator of [`memory`] counts it.
+    pub peak_heap: Option<usize>,
+    /// What the solution warned about while solving, see [`log::warn`].
+    pub warnings: Vec<String>,
+}
+
+/// Outcome of parsing a day's input and solving some of its parts.
+#[derive(Debug)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    pub parse_duration: Duration,
+    /// Most heap allocated while parsing, `None` unless the allocator of [`memory`] counts it.
+    pub parse_peak_heap: Option<usize>,
+    pub parts: Vec<PartRun>,
+}
+
+/// Parses `input` with the solution of `day` of `year` and solves each of `parts` on it.
+///
+/// Panics inside the solution are caught and reported as [`Error::Panicked`], and parts the day
+/// does not have as [`Error::MissingPart`].
+pub fn run_day(year: u16, day: u8, input: &str, parts: &[u8]) -> Result<DayRun, Error> {
+    let day_solution = solution::find(year, day)?;
+
+    let start = Instant::now();
+    let (parsed, parse_peak_heap) = memory::measure(|| {
+        panic::catch_unwind(AssertUnwindSafe(|| day_solution.parse_input(input)))
+    });
+    let parsed = parsed
+        .map_err(|_| Error::Panicked { day, part: None })?
+        .map_err(|e| e.with_day(day))?;
+    let parse_duration = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            log::take_warnings();
+            let start = Instant::now();
+            let (answer, peak_heap) = memory::measure(|| {
+                panic::catch_unwind(AssertUnwindSafe(|| {
+                    day_solution.solve_part(parsed.as_ref(), part)
+                }))
+            });
+            let duration = start.elapsed();
+
+            let answer = match answer {
+                Ok(Some(answer)) => Ok(answer),
+                Ok(None) => Err(Error::MissingPart { day, part }),
+                Err(_) => Err(Error::Panicked {
+                    day,
+                    part: Some(part),
+                }),
+            };
+
+            PartRun {
+                part,
+                answer,
+                duration,
+                peak_heap,
+                warnings: log::take_warnings(),
+            }
+        })
+        .collect();
+
+    Ok(DayRun {
+        year,
+        day,
+        parse_duration,
+        parse_peak_heap,
+        parts,
+    })
+}
+
+/// Runs each of `days`, given as year, day and input, on its input as [`run_day`] does, `jobs`
+/// days at a time.
+///
+/// The days are handed out in order to the first thread free, each one isolated from the
+/// others: one panicking or failing to parse does not stop the rest. The outcomes are returned
+/// in the order of `days`.
+pub fn run_days(
+    days: &[(u16, u8, String)],
+    parts: &[u8],
+    jobs: usize,
+) -> Vec<Result<DayRun, Error>> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new((0..days.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let (year, day, input) = match days.get(idx) {
+                    Some(day) => day,
+                    None => break,
+                };
+                let run = run_day(*year, *day, input, parts);
+                runs.lock().unwrap()[idx] = Some(run);
+            });
+        }
+    });
+
+    runs.into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|run| run.expect("every day is run by a thread"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_day() {
+        let run = run_day(2020, 1, "1721\n979\n366\n299\n675\n1456", &[1, 2, 3]).unwrap();
+
+        assert_eq!((run.year, run.day), (2020, 1));
+        assert_eq!(run.parts.len(), 3);
+        // the allocator of the tests counts the heap
+        assert!(run.parse_peak_heap.unwrap() >= 6 * std::mem::size_of::<i64>());
+        assert!(run.parts[0].peak_heap.is_some());
+        assert_eq!(run.parts[0].answer, Ok(Answer::Number(514579)));
+        assert_eq!(run.parts[1].answer, Ok(Answer::Number(241861950)));
+        assert_eq!(
+            run.parts[2].answer,
+            Err(Error::MissingPart { day: 1, part: 3 })
+        );
+    }
+
+    #[test]
+    fn test_run_day_errors() {
+        assert_eq!(
+            run_day(2020, 26, "", &[1]).unwrap_err(),
+            Error::UnknownDay(26)
+        );
+        assert_eq!(
+            run_day(1999, 1, "", &[1]).unwrap_err(),
+            Error::UnknownYear(1999)
+        );
+        assert!(matches!(
+            run_day(2020, 1, "12\nx", &[1]),
+            Err(Error::Parse {
+                day: Some(1),
+                line: 2,
+                ..
+            })
+        ));
+        // no pair of entries adds up to 2020
+        let run = run_day(2020, 1, "1\n2", &[1]).unwrap();
+        assert_eq!(
+            run.parts[0].answer,
+            Err(Error::Panicked {
+                day: 1,
+                part: Some(1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_days() {
+        let days = vec![
+            (2020, 1, String::from("1721\n979\n366\n299\n675\n1456")),
+            // panics: no pair of entries adds up to 2020
+            (2020, 1, String::from("1\n2")),
+            (2020, 2, String::from("1-3 a: abcde")),
+            (2020, 26, String::new()),
+        ];
+        let runs = run_days(&days, &[1], 3);
+
+        assert_eq!(runs.len(), 4);
+        let answer = |idx: usize| runs[idx].as_ref().unwrap().parts[0].answer.clone();
+        assert_eq!(answer(0), Ok(Answer::Number(514579)));
+        assert!(matches!(answer(1), Err(Error::Panicked { day: 1, .. })));
+        assert_eq!(answer(2), Ok(Answer::Number(1)));
+        assert_eq!(runs[3].as_ref().unwrap_err(), &Error::UnknownDay(26));
+        assert!(run_days(&[], &[1], 4).is_empty());
+    }
+}
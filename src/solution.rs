@@ -0,0 +1,206 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::y2020;
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(i64::try_from(n).expect("Answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(String::from(s))
+    }
+}
+
+/// A day's puzzle: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    /// Days without a second puzzle (or without a solution for it yet) return `None`.
+    fn part2(&self, input: &Self::Input) -> Option<Answer>;
+
+    /// What the input is made of, for linting it: numbers of records, ranges of values, ...
+    fn summary(&self, input: &Self::Input) -> Summary;
+}
+
+/// Type-erased view of a [`Solution`], so that all days can live in the same registry.
+pub trait DynSolution: Sync {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+
+    /// Returns `None` if the day has no such part.
+    fn solve_part(&self, input: &dyn Any, part: u8) -> Option<Answer>;
+
+    fn summarize(&self, input: &dyn Any) -> Summary;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        let input = self.parse(input)?;
+        Ok(Box::new(input))
+    }
+
+    fn solve_part(&self, input: &dyn Any, part: u8) -> Option<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+
+        match part {
+            1 => Some(self.part1(input)),
+            2 => self.part2(input),
+            _ => None,
+        }
+    }
+
+    fn summarize(&self, input: &dyn Any) -> Summary {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+
+        self.summary(input)
+    }
+}
+
+/// Year of the commands that are not told which one.
+pub const DEFAULT_YEAR: u16 = y2020::YEAR;
+
+static YEARS: [(u16, &[&dyn DynSolution]); 1] = [(y2020::YEAR, &y2020::SOLUTIONS)];
+
+/// Years with solutions, in order.
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS.iter().map(|&(year, _)| year)
+}
+
+fn days(year: u16) -> Option<&'static [&'static dyn DynSolution]> {
+    YEARS
+        .iter()
+        .find(|&&(y, _)| y == year)
+        .map(|&(_, days)| days)
+}
+
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    let index = usize::from(day).checked_sub(1)?;
+    days(year)?.get(index).copied()
+}
+
+/// Like [`solution`], telling an unknown year from an unknown day.
+pub fn find(year: u16, day: u8) -> Result<&'static dyn DynSolution, Error> {
+    if days(year).is_none() {
+        return Err(Error::UnknownYear(year));
+    }
+    solution(year, day).ok_or(Error::UnknownDay(day))
+}
+
+/// The days of `year`, none if it has no solutions.
+pub fn solutions(year: u16) -> impl Iterator<Item = (u8, &'static dyn DynSolution)> {
+    days(year)
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(idx, s)| (idx as u8 + 1, *s))
+}
+
+/// Every day of every year, in order.
+pub fn puzzles() -> impl Iterator<Item = (u16, u8)> {
+    years().flat_map(|year| solutions(year).map(move |(day, _)| (year, day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from("abc,def").to_string(), "abc,def");
+    }
+
+    #[test]
+    fn test_solution_lookup() {
+        assert!(solution(2020, 0).is_none());
+        assert!(solution(2020, 1).is_some());
+        assert!(solution(2020, 25).is_some());
+        assert!(solution(2020, 26).is_none());
+        assert!(solution(2019, 1).is_none());
+        assert_eq!(solutions(2020).count(), 25);
+        assert_eq!(solutions(2020).last().map(|(day, _)| day), Some(25));
+        assert_eq!(solutions(2019).count(), 0);
+        assert_eq!(puzzles().next(), Some((2020, 1)));
+        assert!(years().any(|year| year == DEFAULT_YEAR));
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(find(2020, 25).is_ok());
+        assert_eq!(find(2020, 26).err(), Some(Error::UnknownDay(26)));
+        assert_eq!(find(1999, 1).err(), Some(Error::UnknownYear(1999)));
+    }
+
+    #[test]
+    fn test_solve_part() {
+        let day = solution(2020, 1).unwrap();
+        let input = day.parse_input("1721\n979\n366\n299\n675\n1456").unwrap();
+
+        assert_eq!(
+            day.solve_part(input.as_ref(), 1),
+            Some(Answer::Number(514579))
+        );
+        assert_eq!(
+            day.solve_part(input.as_ref(), 2),
+            Some(Answer::Number(241861950))
+        );
+        assert_eq!(day.solve_part(input.as_ref(), 3), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let day = solution(2020, 1).unwrap();
+
+        assert!(matches!(
+            day.parse_input("1721\n97x9"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+}
@@ -1,31 +1,127 @@
-pub mod utils {
-    use std::fs::File;
-    use std::io::BufRead;
-    use std::path::Path;
-    use std::{fs, io, path};
-
-    pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-    where
-        P: AsRef<Path>,
-    {
-        let file = File::open(filename).unwrap();
-        Ok(io::BufReader::new(file).lines())
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Splits the input into blocks separated by blank lines, along with the index of each block's
+/// first line in the input (so that errors can point at the right line).
+///
+/// Works for both LF and CRLF line endings: lines are trimmed at the end, so lines holding only
+/// whitespace count as blank, and each block is joined back together with `\n`.
+pub fn read_numbered_blocks(input: &str) -> Vec<(usize, String)> {
+    let mut blocks = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+    let mut first_line = 0;
+
+    for (idx, line) in input.lines().map(str::trim_end).enumerate() {
+        if line.is_empty() {
+            if !block.is_empty() {
+                blocks.push((first_line, block.join("\n")));
+                block.clear();
+            }
+        } else {
+            if block.is_empty() {
+                first_line = idx;
+            }
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push((first_line, block.join("\n")));
     }
 
-    pub fn read_lines_until_empty<P>(filename: P) -> Vec<String>
-    where
-        P: AsRef<Path>,
-    {
-        fs::read_to_string(filename)
-            .unwrap()
-            .split("\r\n\r\n")
-            .map(|s| s.to_string())
-            .collect()
+    blocks
+}
+
+/// Parses every line of the input, reporting errors at the line they were found on.
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&str) -> Result<T, Error>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| e.offset_lines(idx)))
+        .collect()
+}
+
+/// Parses every block of the input, reporting errors at the line they were found on.
+pub fn parse_blocks<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&str) -> Result<T, Error>,
+{
+    read_numbered_blocks(input)
+        .iter()
+        .map(|(first_line, block)| parse(block).map_err(|e| e.offset_lines(*first_line)))
+        .collect()
+}
+
+/// Parses `text`, a part of `line`, as a number.
+pub fn parse_number<T: FromStr>(line: &str, text: &str) -> Result<T, Error> {
+    text.parse()
+        .map_err(|_| Error::parse(line, text, "invalid number"))
+}
+
+pub fn convert_lines_to_numbers(input: &str) -> Result<Vec<i64>, Error> {
+    parse_lines(input, |line| parse_number(line, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_numbered_blocks_lf() {
+        assert_eq!(
+            read_numbered_blocks("a\nb\n\nc\n"),
+            vec![(0, String::from("a\nb")), (3, String::from("c"))]
+        );
+    }
+
+    #[test]
+    fn test_read_numbered_blocks_crlf() {
+        assert_eq!(
+            read_numbered_blocks("a\r\nb\r\n\r\nc\r\n"),
+            vec![(0, String::from("a\nb")), (3, String::from("c"))]
+        );
+        assert_eq!(
+            read_numbered_blocks("\r\na\r\n\r\n\r\nb\r\nc"),
+            vec![(1, String::from("a")), (4, String::from("b\nc"))]
+        );
     }
 
-    pub fn convert_lines_to_numbers(lines: io::Lines<io::BufReader<File>>) -> Vec<i64> {
-        lines
-            .map(|line| line.unwrap().parse::<i64>().unwrap())
-            .collect()
+    #[test]
+    fn test_read_numbered_blocks_whitespace() {
+        assert_eq!(
+            read_numbered_blocks("\na  \nb\t\n \n\n\t\nc\n\n"),
+            vec![(1, String::from("a\nb")), (6, String::from("c"))]
+        );
+        assert!(read_numbered_blocks("").is_empty());
+        assert!(read_numbered_blocks(" \r\n\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_convert_lines_to_numbers() {
+        assert_eq!(convert_lines_to_numbers("1\n-2\n"), Ok(vec![1, -2]));
+        assert_eq!(
+            convert_lines_to_numbers("1\n2\n3x\n"),
+            Err(Error::Parse {
+                day: None,
+                line: 3,
+                column: 1,
+                text: String::from("3x"),
+                message: String::from("invalid number"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let result = parse_blocks("1\n\n2\n3\n\nx", |block| {
+            parse_lines(block, |line| parse_number::<u32>(line, line))
+        });
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 6, column 1: invalid number: 'x'"
+        );
     }
 }
@@ -0,0 +1,403 @@
+//! Stepping through the frames of a simulation in a terminal.
+//!
+//! The frames of [`crate::export`] are drawn with ANSI escapes, each character showing two cells
+//! stacked on top of each other, and played one after the other. Keys are read from the
+//! terminal one at a time:
+//!
+//! - space: pause or resume
+//! - `n` or right arrow: next frame, `p` or left arrow: previous frame
+//! - `g` and `G`: first and last frame
+//! - `+` and `-`: play faster or slower
+//! - `q` or ctrl-c: quit
+//!
+//! Frames larger than the terminal are cropped to its top left corner.
+
+use crate::error::Error;
+use crate::export::Color;
+use crate::grid::Grid;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Time each frame is shown while playing, unless told otherwise.
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+const MIN_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+const TERMINAL: &str = "/dev/tty";
+
+/// Lines below the frame, for the status and the keys.
+const STATUS_LINES: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Pause,
+    Forward,
+    Back,
+    First,
+    Last,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// The controls typed as `bytes`, ignoring the keys that mean nothing.
+pub fn controls(bytes: &[u8]) -> Vec<Control> {
+    let mut controls = Vec::new();
+    let mut rest = bytes;
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        let control = match byte {
+            b' ' => Control::Pause,
+            b'n' => Control::Forward,
+            b'p' => Control::Back,
+            b'g' => Control::First,
+            b'G' => Control::Last,
+            b'+' | b'=' => Control::Faster,
+            b'-' => Control::Slower,
+            // ctrl-c comes in as a key too, the terminal does not turn it into a signal
+            b'q' | 0x03 => Control::Quit,
+            // arrows are sent as ESC [ C and ESC [ D
+            0x1b => match rest {
+                [b'[', b'C', tail @ ..] => {
+                    rest = tail;
+                    Control::Forward
+                }
+                [b'[', b'D', tail @ ..] => {
+                    rest = tail;
+                    Control::Back
+                }
+                _ => continue,
+            },
+            _ => continue,
+        };
+        controls.push(control);
+    }
+    controls
+}
+
+/// Position in a sequence of frames, and whether it moves on by itself.
+#[derive(Debug)]
+pub struct Player {
+    frames: usize,
+    position: usize,
+    playing: bool,
+    delay: Duration,
+}
+
+impl Player {
+    /// Plays `frames` frames from the first one, showing each for `delay`.
+    pub fn new(frames: usize, delay: Duration) -> Player {
+        Player {
+            frames: frames.max(1),
+            position: 0,
+            playing: true,
+            delay: delay.clamp(MIN_DELAY, MAX_DELAY),
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    fn last(&self) -> usize {
+        self.frames - 1
+    }
+
+    /// Returns `false` once told to quit.
+    pub fn apply(&mut self, control: Control) -> bool {
+        match control {
+            Control::Pause => {
+                self.playing = !self.playing;
+                // resuming at the end plays it all again
+                if self.playing && self.position == self.last() {
+                    self.position = 0;
+                }
+            }
+            Control::Forward => {
+                self.playing = false;
+                self.position = (self.position + 1).min(self.last());
+            }
+            Control::Back => {
+                self.playing = false;
+                self.position = self.position.saturating_sub(1);
+            }
+            Control::First => self.position = 0,
+            Control::Last => self.position = self.last(),
+            Control::Faster => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Control::Slower => self.delay = (self.delay * 2).min(MAX_DELAY),
+            Control::Quit => return false,
+        }
+        true
+    }
+
+    /// Moves to the next frame if playing, pausing on the last one.
+    pub fn tick(&mut self) {
+        if self.playing {
+            self.position = (self.position + 1).min(self.last());
+            if self.position == self.last() {
+                self.playing = false;
+            }
+        }
+    }
+}
+
+fn set_foreground(text: &mut String, Color(r, g, b): Color) {
+    let _ = write!(text, "\x1b[38;2;{};{};{}m", r, g, b);
+}
+
+fn set_background(text: &mut String, Color(r, g, b): Color) {
+    let _ = write!(text, "\x1b[48;2;{};{};{}m", r, g, b);
+}
+
+/// `frame` as at most `lines` lines of at most `columns` characters, the upper half block of
+/// each character in the color of a cell and its background in the color of the cell below.
+pub fn draw(frame: &Grid<Color>, columns: usize, lines: usize) -> String {
+    let width = frame.width().min(columns);
+    let height = frame.height().min(lines * 2);
+
+    let mut text = String::new();
+    for y in (0..height).step_by(2) {
+        let (mut foreground, mut background) = (None, None);
+        for x in 0..width {
+            let top = frame[(x, y)];
+            if foreground != Some(top) {
+                set_foreground(&mut text, top);
+                foreground = Some(top);
+            }
+            // the last line of an odd number of rows keeps the background of the terminal
+            let bottom = frame.get(x, y + 1).filter(|_| y + 1 < height).copied();
+            if background != Some(bottom) {
+                match bottom {
+                    Some(bottom) => set_background(&mut text, bottom),
+                    None => text.push_str("\x1b[49m"),
+                }
+                background = Some(bottom);
+            }
+            text.push('▀');
+        }
+        text.push_str("\x1b[0m\x1b[K\n");
+    }
+    text
+}
+
+/// Runs `stty` on the terminal with `args`, returning what it printed.
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(File::open(TERMINAL)?)
+        .stderr(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("stty failed"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Columns and lines of the terminal, 80 by 24 if it does not say.
+fn terminal_size() -> (usize, usize) {
+    let size = stty(&["size"]).ok().and_then(|size| {
+        let mut numbers = size.split_whitespace().map(|n| n.parse::<usize>().ok());
+        let lines = numbers.next()??;
+        let columns = numbers.next()??;
+        Some((columns, lines))
+    });
+    size.unwrap_or((80, 24))
+}
+
+/// Puts the terminal back the way it was found when dropped.
+struct Screen {
+    settings: String,
+}
+
+impl Screen {
+    /// Reads keys as soon as they are typed, without echoing them or turning ctrl-c into an
+    /// interrupt that would leave the terminal in that state, on an alternate screen.
+    fn open() -> io::Result<Screen> {
+        let settings = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        io::stdout().flush()?;
+        Ok(Screen { settings })
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[&self.settings]);
+    }
+}
+
+/// Plays `frames` in the terminal until told to quit, starting with a frame every `delay`.
+pub fn view(frames: &[Grid<Color>], title: &str, delay: Duration) -> Result<(), Error> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+    let no_terminal = |e: io::Error| Error::Input(format!("could not open a terminal: {}", e));
+
+    // keys come from the terminal itself, the input may be on stdin
+    let mut terminal = File::open(TERMINAL).map_err(no_terminal)?;
+    let screen = Screen::open().map_err(no_terminal)?;
+    let (columns, lines) = terminal_size();
+    let lines = lines.saturating_sub(STATUS_LINES).max(1);
+
+    let (keys, typed) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buffer = [0; 16];
+        while let Ok(len) = terminal.read(&mut buffer) {
+            if len == 0 || keys.send(buffer[..len].to_vec()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut player = Player::new(frames.len(), delay);
+    loop {
+        let frame = &frames[player.position()];
+        let cropped = if frame.width() > columns || frame.height() > lines * 2 {
+            format!(" (cropped from {}x{})", frame.width(), frame.height())
+        } else {
+            String::new()
+        };
+        let mut stdout = io::stdout().lock();
+        let _ = write!(
+            stdout,
+            "\x1b[H{}\x1b[J{}  frame {}/{}  {}  {}ms{}\n\
+             space pause  n/\u{2192} next  p/\u{2190} previous  g/G first/last  +/- speed  q quit",
+            draw(frame, columns, lines),
+            title,
+            player.position() + 1,
+            frames.len(),
+            if player.playing() {
+                "playing"
+            } else {
+                "paused"
+            },
+            player.delay().as_millis(),
+            cropped
+        );
+        let _ = stdout.flush();
+        drop(stdout);
+
+        let bytes = if player.playing() {
+            match typed.recv_timeout(player.delay()) {
+                Ok(bytes) => bytes,
+                Err(RecvTimeoutError::Timeout) => {
+                    player.tick();
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        } else {
+            match typed.recv() {
+                Ok(bytes) => bytes,
+                Err(_) => break,
+            }
+        };
+        if !controls(&bytes)
+            .into_iter()
+            .all(|control| player.apply(control))
+        {
+            break;
+        }
+    }
+
+    drop(screen);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controls() {
+        assert_eq!(
+            controls(b"n\x1b[Cx\x1b[D q\x1b"),
+            vec![
+                Control::Forward,
+                Control::Forward,
+                Control::Back,
+                Control::Pause,
+                Control::Quit
+            ]
+        );
+        assert_eq!(
+            controls(b"gG+-\x03"),
+            vec![
+                Control::First,
+                Control::Last,
+                Control::Faster,
+                Control::Slower,
+                Control::Quit
+            ]
+        );
+    }
+
+    #[test]
+    fn test_player() {
+        let mut player = Player::new(3, DEFAULT_DELAY);
+        assert!(player.playing());
+
+        player.tick();
+        player.tick();
+        assert_eq!(player.position(), 2);
+        assert!(!player.playing());
+
+        assert!(player.apply(Control::Back));
+        assert_eq!(player.position(), 1);
+        player.apply(Control::Forward);
+        player.apply(Control::Forward);
+        assert_eq!(player.position(), 2);
+
+        // resuming at the end starts over
+        player.apply(Control::Pause);
+        assert_eq!((player.position(), player.playing()), (0, true));
+        player.apply(Control::Last);
+        assert_eq!(player.position(), 2);
+
+        player.apply(Control::Faster);
+        assert_eq!(player.delay(), DEFAULT_DELAY / 2);
+        for _ in 0..20 {
+            player.apply(Control::Slower);
+        }
+        assert_eq!(player.delay(), MAX_DELAY);
+
+        assert!(!player.apply(Control::Quit));
+    }
+
+    #[test]
+    fn test_draw() {
+        let red = Color(255, 0, 0);
+        let frame = Grid::from_fn(3, 3, |x, y| if x == y { red } else { Color::BLACK });
+
+        assert_eq!(
+            draw(&frame, 80, 24),
+            "\x1b[38;2;255;0;0m\x1b[48;2;0;0;0m▀\x1b[38;2;0;0;0m\x1b[48;2;255;0;0m▀\
+             \x1b[48;2;0;0;0m▀\x1b[0m\x1b[K\n\
+             \x1b[38;2;0;0;0m\x1b[49m▀▀\x1b[38;2;255;0;0m▀\x1b[0m\x1b[K\n"
+        );
+
+        // cropped to one line of two columns
+        assert_eq!(
+            draw(&frame, 2, 1),
+            "\x1b[38;2;255;0;0m\x1b[48;2;0;0;0m▀\x1b[38;2;0;0;0m\x1b[48;2;255;0;0m▀\
+             \x1b[0m\x1b[K\n"
+        );
+    }
+}
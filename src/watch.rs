@@ -0,0 +1,250 @@
+//! Rerunning a day whenever its input changes, to see what an edit of the input does.
+//!
+//! A [`Snapshot`] holds the answers of a day on its worked examples and on its input. The
+//! input file is polled by a [`Poller`], and each time its contents change the day is run
+//! again and the answers that differ from the previous [`Snapshot`] are reported as
+//! [`Change`]s.
+//!
+//! The solvers are compiled into the binary: an edit of their source only shows once the
+//! binary is rebuilt and the watch restarted.
+
+use crate::error::Error;
+use crate::fixtures::Example;
+use crate::runner;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Time between two looks at the input, unless told otherwise.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What a day was run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Source {
+    /// The example starting at this line of the fixture file.
+    Example(usize),
+    Input,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Example(line) => write!(f, "example at line {}", line),
+            Source::Input => write!(f, "input"),
+        }
+    }
+}
+
+/// Answers of a day, keyed by what it was run on and the part, `None` for an input that could
+/// not be parsed. Failures are kept as their message.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    answers: BTreeMap<(Source, Option<u8>), Result<String, String>>,
+}
+
+impl Snapshot {
+    /// Runs both parts of `day` of `year` on each of `examples`, and on `input` if there is one.
+    pub fn take(year: u16, day: u8, examples: &[Example], input: Option<&str>) -> Snapshot {
+        let mut snapshot = Snapshot::default();
+        let sources = examples
+            .iter()
+            .map(|example| (Source::Example(example.line), example.input.as_str()))
+            .chain(input.map(|input| (Source::Input, input)));
+
+        for (source, text) in sources {
+            match runner::run_day(year, day, text, &[1, 2]) {
+                Ok(run) => {
+                    for part_run in run.parts {
+                        let answer = match part_run.answer {
+                            Ok(answer) => Ok(answer.to_string()),
+                            Err(Error::MissingPart { .. }) => continue,
+                            Err(e) => Err(e.to_string()),
+                        };
+                        snapshot
+                            .answers
+                            .insert((source, Some(part_run.part)), answer);
+                    }
+                }
+                Err(e) => {
+                    snapshot.answers.insert((source, None), Err(e.to_string()));
+                }
+            }
+        }
+        snapshot
+    }
+
+    /// Every answer, as [`Change`]s from nothing.
+    pub fn answers(&self) -> Vec<Change> {
+        Snapshot::default().changes(self)
+    }
+
+    /// The answers of `next` that differ from these ones.
+    pub fn changes(&self, next: &Snapshot) -> Vec<Change> {
+        let mut keys: Vec<_> = self.answers.keys().chain(next.answers.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        keys.into_iter()
+            .filter_map(|key| {
+                let before = self.answers.get(key);
+                let after = next.answers.get(key);
+                if before == after {
+                    return None;
+                }
+                Some(Change {
+                    source: key.0,
+                    part: key.1,
+                    before: before.cloned(),
+                    after: after.cloned(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// An answer that appeared, disappeared or changed between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub source: Source,
+    /// `None` for the parsing of the input.
+    pub part: Option<u8>,
+    pub before: Option<Result<String, String>>,
+    pub after: Option<Result<String, String>>,
+}
+
+fn write_answer(f: &mut fmt::Formatter<'_>, answer: &Result<String, String>) -> fmt::Result {
+    match answer {
+        Ok(answer) => write!(f, "{}", answer),
+        Err(message) => write!(f, "error: {}", message),
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "{} part {}: ", self.source, part)?,
+            None => write!(f, "{}: ", self.source)?,
+        }
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => {
+                write_answer(f, before)?;
+                write!(f, " -> ")?;
+                write_answer(f, after)
+            }
+            (None, Some(after)) => write_answer(f, after),
+            (Some(before), None) => {
+                write!(f, "gone, was ")?;
+                write_answer(f, before)
+            }
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Contents of a file, as they change from one poll to the next.
+#[derive(Debug)]
+pub struct Poller {
+    path: PathBuf,
+    last: Option<String>,
+    failed: bool,
+}
+
+impl Poller {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Poller {
+        Poller {
+            path: path.into(),
+            last: None,
+            failed: false,
+        }
+    }
+
+    /// The contents of the file if they changed since the last poll. A file that cannot be read
+    /// is reported once, as `Err` with the message of the failure, and as changed once it can
+    /// be read again.
+    pub fn poll(&mut self) -> Option<Result<String, String>> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) if self.last.as_ref() == Some(&contents) => None,
+            Ok(contents) => {
+                self.failed = false;
+                self.last = Some(contents.clone());
+                Some(Ok(contents))
+            }
+            Err(_) if self.failed => None,
+            Err(e) => {
+                self.failed = true;
+                self.last = None;
+                Some(Err(format!(
+                    "could not read {}: {}",
+                    self.path.display(),
+                    e
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(line: usize, input: &str) -> Example {
+        Example {
+            line,
+            input: String::from(input),
+            expected: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let examples = [example(1, "1721\n979\n366\n299\n675\n1456")];
+        let before = Snapshot::take(2020, 1, &examples, Some("1010\n1010\n0"));
+        let after = Snapshot::take(2020, 1, &examples, Some("1000\n1020\n0\n12x"));
+
+        let answers: Vec<String> = before.answers().iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            answers,
+            vec![
+                "example at line 1 part 1: 514579",
+                "example at line 1 part 2: 241861950",
+                "input part 1: 1020100",
+                "input part 2: 0"
+            ]
+        );
+
+        let changes: Vec<String> = before
+            .changes(&after)
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                "input: error: day 1, line 4, column 1: invalid number: '12x'",
+                "input part 1: gone, was 1020100",
+                "input part 2: gone, was 0"
+            ]
+        );
+        assert!(after.changes(&after).is_empty());
+    }
+
+    #[test]
+    fn test_poller() {
+        let path =
+            std::env::temp_dir().join(format!("code-advent-2020-watch-{}.txt", std::process::id()));
+        let mut poller = Poller::new(&path);
+
+        assert!(matches!(poller.poll(), Some(Err(_))));
+        assert_eq!(poller.poll(), None);
+        fs::write(&path, "1\n").unwrap();
+        assert_eq!(poller.poll(), Some(Ok(String::from("1\n"))));
+        assert_eq!(poller.poll(), None);
+        fs::write(&path, "2\n").unwrap();
+        assert_eq!(poller.poll(), Some(Ok(String::from("2\n"))));
+
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(poller.poll(), Some(Err(_))));
+    }
+}
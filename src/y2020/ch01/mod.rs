@@ -0,0 +1,91 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::convert_lines_to_numbers;
+use std::collections::{HashMap, HashSet};
+
+fn find_2_adding_numbers(numbers: &[i64]) -> (i64, i64) {
+    let mut s: HashSet<i64> = HashSet::new();
+
+    for &num in numbers {
+        // look for the number's complement in the set;
+        let c = 2020 - num;
+
+        if !s.contains(&c) {
+            s.insert(num);
+        } else {
+            return (num, c);
+        }
+    }
+
+    panic!("no 2 numbers that add up to 2020 found!")
+}
+
+fn find_3_adding_numbers(numbers: &[i64]) -> (i64, i64, i64) {
+    let mut m: HashMap<i64, i32> = HashMap::new();
+
+    for &num in numbers {
+        m.entry(num).and_modify(|v| *v += 1).or_insert(1);
+    }
+
+    for (num1, c1) in &m {
+        for num2 in m.keys() {
+            if num1 == num2 && *c1 == 1 {
+                continue;
+            }
+
+            let c = 2020 - num1 - num2;
+
+            if let Some(c3) = m.get(&c) {
+                if c == *num1 && *c3 == 2 {
+                    continue;
+                }
+                return (*num1, *num2, c);
+            }
+        }
+    }
+
+    panic!("no 3 numbers thad add up to 2020 found!")
+}
+
+/// A report of `size` entries (at least 5), two of which add up to 2020, and three others too.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let pair = rng.range(1, 2019);
+    let first = rng.range(1, 2018);
+    let second = rng.range(1, 2019 - first);
+
+    let mut entries = vec![pair, 2020 - pair, first, second, 2020 - first - second];
+    while entries.len() < size {
+        entries.push(rng.range(1, 2019));
+    }
+    rng.shuffle(&mut entries);
+
+    entries.iter().map(|entry| format!("{}\n", entry)).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, Error> {
+        convert_lines_to_numbers(input)
+    }
+
+    fn summary(&self, numbers: &Vec<i64>) -> Summary {
+        Summary::new()
+            .fact("entries", numbers.len())
+            .range("values", numbers.iter())
+    }
+
+    fn part1(&self, numbers: &Vec<i64>) -> Answer {
+        let (a, b) = find_2_adding_numbers(numbers);
+        Answer::from(a * b)
+    }
+
+    fn part2(&self, numbers: &Vec<i64>) -> Option<Answer> {
+        let (a, b, c) = find_3_adding_numbers(numbers);
+        Some(Answer::from(a * b * c))
+    }
+}
@@ -0,0 +1,171 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::parse::{self, Cursor};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_lines;
+
+#[derive(Debug)]
+struct PasswordPolicy {
+    character: char,
+    lower_limit: i32,
+    upper_limit: i32,
+}
+
+fn parse_policy(cursor: &mut Cursor) -> Result<PasswordPolicy, Error> {
+    let lower_limit = cursor.unsigned()?;
+    cursor.tag("-")?;
+    let upper_limit = cursor.unsigned()?;
+    cursor.tag(" ")?;
+
+    let character = cursor.word()?;
+    let mut chars = character.chars();
+    let character = match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_lowercase() => c,
+        _ => return Err(cursor.error(character, "expected a lowercase letter")),
+    };
+
+    Ok(PasswordPolicy {
+        character,
+        lower_limit,
+        upper_limit,
+    })
+}
+
+#[derive(Debug)]
+pub struct Password {
+    policy: PasswordPolicy,
+    value: String,
+}
+
+fn parse_password(line: &str) -> Result<Password, Error> {
+    parse::line(line, |cursor| {
+        if !cursor.rest().contains(':') {
+            return Err(cursor.error(line, "missing ':' before the password"));
+        }
+        let policy = parse_policy(cursor)?;
+        cursor.tag(":")?;
+        cursor.skip_whitespace();
+
+        let value = cursor.take_while(|c| !c.is_whitespace());
+        if let Some(c) = value.chars().find(|c| !c.is_ascii_lowercase()) {
+            return Err(cursor.error(value, format!("invalid password character '{}'", c)));
+        }
+
+        Ok(Password {
+            policy,
+            value: value.to_string(),
+        })
+    })
+}
+
+const DELTA: usize = 'a' as usize;
+
+fn char_to_index(c: char) -> usize {
+    c as usize - DELTA
+}
+
+fn is_password_valid_part1(p: &Password) -> bool {
+    let mut char_frequency: [i32; 26] = [0; 26];
+
+    for c in p.value.chars() {
+        char_frequency[char_to_index(c)] += 1
+    }
+
+    let freq = char_frequency[char_to_index(p.policy.character)];
+
+    freq >= p.policy.lower_limit && freq <= p.policy.upper_limit
+}
+
+fn is_password_valid_part2(p: &Password) -> bool {
+    let policy = &p.policy;
+
+    let mut cnt = 0;
+
+    for (idx, c) in p.value.char_indices() {
+        let position = idx + 1;
+        if (position == policy.lower_limit as usize || position == policy.upper_limit as usize)
+            && c == p.policy.character
+        {
+            cnt += 1;
+        } else if position > policy.upper_limit as usize {
+            break;
+        }
+    }
+
+    cnt == 1
+}
+
+fn get_invalid_passwords_count_part1(passwords: &[Password]) -> usize {
+    passwords
+        .iter()
+        .filter(|p| is_password_valid_part1(p))
+        .count()
+}
+
+fn get_invalid_passwords_count_part2(passwords: &[Password]) -> usize {
+    passwords
+        .iter()
+        .filter(|p| is_password_valid_part2(p))
+        .count()
+}
+
+/// `size` passwords along with their policies.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let lower = rng.range(1, 10);
+        let upper = rng.range(lower + 1, lower + 10);
+        let character = rng.letter();
+
+        // mostly long enough for the second policy to look at both positions
+        let len = rng.range(upper.saturating_sub(3).max(1), upper + 5);
+        let password: String = (0..len)
+            .map(|_| {
+                if rng.chance(40) {
+                    character
+                } else {
+                    rng.letter()
+                }
+            })
+            .collect();
+
+        input.push_str(&format!(
+            "{}-{} {}: {}\n",
+            lower, upper, character, password
+        ));
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Password>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Password>, Error> {
+        parse_lines(input, parse_password)
+    }
+
+    fn summary(&self, passwords: &Vec<Password>) -> Summary {
+        Summary::new()
+            .fact("passwords", passwords.len())
+            .range(
+                "lower limits",
+                passwords.iter().map(|p| p.policy.lower_limit),
+            )
+            .range(
+                "upper limits",
+                passwords.iter().map(|p| p.policy.upper_limit),
+            )
+            .range("lengths", passwords.iter().map(|p| p.value.len()))
+    }
+
+    fn part1(&self, passwords: &Vec<Password>) -> Answer {
+        Answer::from(get_invalid_passwords_count_part1(passwords))
+    }
+
+    fn part2(&self, passwords: &Vec<Password>) -> Option<Answer> {
+        Some(Answer::from(get_invalid_passwords_count_part2(passwords)))
+    }
+}
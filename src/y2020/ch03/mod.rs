@@ -0,0 +1,143 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::export::Color;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use crate::trace;
+
+#[derive(Debug)]
+enum MapTile {
+    Open,
+    Tree,
+}
+
+impl MapTile {
+    fn from_char(c: char) -> Option<MapTile> {
+        match c {
+            '.' => Some(MapTile::Open),
+            '#' => Some(MapTile::Tree),
+            _ => None,
+        }
+    }
+}
+
+/// The pattern of the map repeats to the right.
+#[derive(Debug)]
+pub struct Map {
+    pattern: Grid<MapTile>,
+}
+
+struct Slope {
+    x: usize,
+    y: usize,
+}
+
+/// The slopes of part 2, the second one being the slope of part 1.
+const SLOPES: [Slope; 5] = [
+    Slope { x: 1, y: 1 },
+    Slope { x: 3, y: 1 },
+    Slope { x: 5, y: 1 },
+    Slope { x: 7, y: 1 },
+    Slope { x: 1, y: 2 },
+];
+
+impl Map {
+    fn count_tree_for_slope(&self, slope: &Slope) -> i64 {
+        let mut x: usize = 0;
+        let mut y: usize = 0;
+        let mut cnt = 0;
+
+        while y < self.pattern.height() {
+            if let Some(MapTile::Tree) = self.pattern.get_wrapping(x as isize, y as isize) {
+                cnt += 1;
+            }
+            x += slope.x;
+            y += slope.y;
+        }
+
+        cnt
+    }
+
+    /// The map repeated to the right as far as `slope` goes, with its path highlighted.
+    fn render_slope(&self, slope: &Slope) -> Grid<Color> {
+        let height = self.pattern.height();
+        let pattern_width = self.pattern.width().max(1);
+        let last_x = height.saturating_sub(1) / slope.y * slope.x;
+        let width = (last_x / pattern_width + 1) * pattern_width;
+
+        Grid::from_fn(width, height, |x, y| {
+            let on_path = y % slope.y == 0 && x == y / slope.y * slope.x;
+            let tile = self.pattern.get_wrapping(x as isize, y as isize);
+            match (tile, on_path) {
+                (Some(MapTile::Tree), false) => Color(34, 139, 34),
+                (Some(MapTile::Tree), true) => Color(220, 20, 60),
+                (_, false) => Color(245, 245, 235),
+                (_, true) => Color(100, 149, 237),
+            }
+        })
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map, Error> {
+    let pattern = Grid::parse(input, MapTile::from_char)?;
+
+    Ok(Map { pattern })
+}
+
+/// A map of `size` rows (at least 1) of 31 squares, about one in five with a tree.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let pattern = Grid::from_fn(31, size.max(1), |_, _| rng.chance(20));
+    pattern.render(|&tree| if tree { '#' } else { '.' })
+}
+
+/// The way down the map along the slope of part 1, or one frame per slope of part 2.
+pub(crate) fn frames(input: &str, part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    let map = parse_map(input)?;
+    let slopes = match part {
+        1 => &SLOPES[1..2],
+        _ => &SLOPES[..],
+    };
+
+    Ok(slopes.iter().map(|slope| map.render_slope(slope)).collect())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Map, Error> {
+        let map = parse_map(input)?;
+        trace!(
+            "map of {}x{}:\n{}",
+            map.pattern.width(),
+            map.pattern.height(),
+            map.pattern.render(|tile| match tile {
+                MapTile::Open => '.',
+                MapTile::Tree => '#',
+            })
+        );
+        Ok(map)
+    }
+
+    fn summary(&self, m: &Map) -> Summary {
+        Summary::new()
+            .fact("rows", m.pattern.height())
+            .fact("columns", m.pattern.width())
+            .fact(
+                "trees",
+                m.pattern.count(|tile| matches!(tile, MapTile::Tree)),
+            )
+    }
+
+    fn part1(&self, m: &Map) -> Answer {
+        Answer::from(m.count_tree_for_slope(&SLOPES[1]))
+    }
+
+    fn part2(&self, m: &Map) -> Option<Answer> {
+        let n: i64 = SLOPES.iter().map(|s| m.count_tree_for_slope(s)).product();
+
+        Some(Answer::from(n))
+    }
+}
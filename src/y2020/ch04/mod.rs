@@ -0,0 +1,280 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::utils::{parse_blocks, parse_lines};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct Passport {
+    values: HashMap<String, String>,
+}
+
+impl Passport {
+    fn is_valid(&self) -> bool {
+        if self.values.contains_key("cid") {
+            self.values.len() == 8
+        } else {
+            self.values.len() == 7
+        }
+    }
+
+    fn is_valid_part2(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+
+        for (field, value) in &self.values {
+            if !validate_field(field, value) {
+                trace!("{} => {} invalid", field, value);
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn read_passport(block: &str) -> Result<Passport, Error> {
+    let mut passport = HashMap::new();
+
+    parse_lines(block, |line| {
+        for p in line.split_whitespace() {
+            let mut it = p.split(":");
+            let key: String = String::from(it.next().unwrap_or(p));
+            let value: String = match it.next() {
+                Some(value) => String::from(value),
+                None => return Err(Error::parse(line, p, "expected a key:value field")),
+            };
+
+            passport.insert(key, value);
+        }
+        Ok(())
+    })?;
+
+    Ok(Passport { values: passport })
+}
+
+fn read_passports(input: &str) -> Result<Vec<Passport>, Error> {
+    parse_blocks(input, read_passport)
+}
+
+fn validate_number(value: &str, from: i32, to: i32) -> bool {
+    match value.parse::<i32>() {
+        Ok(num) => num >= from && num <= to,
+        Err(_) => false,
+    }
+}
+
+fn validate_eye_color(x: &str) -> bool {
+    let mut s: HashSet<&str> = HashSet::new();
+    s.insert("amb");
+    s.insert("blu");
+    s.insert("brn");
+    s.insert("gry");
+    s.insert("grn");
+    s.insert("hzl");
+    s.insert("oth");
+
+    s.contains(&x)
+}
+
+fn validate_height(s: &str) -> bool {
+    let n = s.len() - 2;
+    if s.len() < 4 {
+        return false;
+    }
+
+    if s.ends_with("cm") {
+        validate_number(s.split_at(n).0, 150, 193)
+    } else if s.ends_with("in") {
+        validate_number(s.split_at(n).0, 59, 76)
+    } else {
+        false
+    }
+}
+
+fn validate_hair_color(s: &str) -> bool {
+    for (pos, ch) in s.chars().enumerate() {
+        if pos == 0 {
+            if ch != '#' {
+                return false;
+            }
+        } else if pos <= 6 {
+            if !ch.to_ascii_lowercase().is_ascii_alphanumeric() {
+                return false;
+            }
+        } else if pos > 6 {
+            return false;
+        }
+    }
+    true
+}
+
+fn validate_pid(s: &str) -> bool {
+    s.len() == 9 && s.chars().all(|c| c.is_numeric())
+}
+
+fn validate_cid(_s: &str) -> bool {
+    true
+}
+
+fn validate_field(field: &str, value: &str) -> bool {
+    match field {
+        "byr" => validate_number(value, 1920, 2002),
+        "iyr" => validate_number(value, 2010, 2020),
+        "eyr" => validate_number(value, 2020, 2030),
+        "hgt" => validate_height(value),
+        "hcl" => validate_hair_color(value),
+        "ecl" => validate_eye_color(value),
+        "pid" => validate_pid(value),
+        "cid" => validate_cid(value),
+        _ => false,
+    }
+}
+
+const FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// A random value for `field`, which passes its validation if `valid`.
+fn generate_value(rng: &mut Rng, field: &str, valid: bool) -> String {
+    let year = |rng: &mut Rng, from: u64, to: u64| {
+        if valid {
+            rng.range(from, to)
+        } else if rng.chance(50) {
+            rng.range(from - 20, from - 1)
+        } else {
+            rng.range(to + 1, to + 20)
+        }
+    };
+
+    match field {
+        "byr" => year(rng, 1920, 2002).to_string(),
+        "iyr" => year(rng, 2010, 2020).to_string(),
+        "eyr" => year(rng, 2020, 2030).to_string(),
+        "hgt" => match (valid, rng.chance(50)) {
+            (true, true) => format!("{}cm", rng.range(150, 193)),
+            (true, false) => format!("{}in", rng.range(59, 76)),
+            (false, true) => format!("{}cm", rng.range(100, 149)),
+            (false, false) => rng.range(59, 193).to_string(),
+        },
+        "hcl" => format!(
+            "{}{:06x}",
+            if valid { "#" } else { "" },
+            rng.range(0, 0xff_ffff)
+        ),
+        "ecl" if valid => String::from(*rng.choose(&EYE_COLORS)),
+        "ecl" => String::from(*rng.choose(&["blk", "red", "zzz", "wat"])),
+        "pid" => {
+            let digits = if valid { 9 } else { *rng.choose(&[8, 10]) };
+            (0..digits).map(|_| rng.range(0, 9).to_string()).collect()
+        }
+        _ => rng.range(100, 350).to_string(),
+    }
+}
+
+/// `size` passports: most of them valid, the others missing fields or holding invalid values.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut passports = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut fields = Vec::new();
+        for &field in &FIELDS {
+            let missing = if field == "cid" {
+                rng.chance(40)
+            } else {
+                rng.chance(5)
+            };
+            if !missing {
+                let valid = rng.chance(90);
+                fields.push(format!("{}:{}", field, generate_value(rng, field, valid)));
+            }
+        }
+        if fields.is_empty() {
+            fields.push(format!("cid:{}", rng.range(100, 350)));
+        }
+        rng.shuffle(&mut fields);
+
+        let mut passport = String::new();
+        for (idx, field) in fields.iter().enumerate() {
+            if idx > 0 {
+                passport.push(if rng.chance(30) { '\n' } else { ' ' });
+            }
+            passport.push_str(field);
+        }
+        passports.push(passport);
+    }
+
+    passports.join("\n\n") + "\n"
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Passport>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Passport>, Error> {
+        read_passports(input)
+    }
+
+    fn summary(&self, passports: &Vec<Passport>) -> Summary {
+        Summary::new()
+            .fact("passports", passports.len())
+            .range("fields", passports.iter().map(|p| p.values.len()))
+    }
+
+    fn part1(&self, passports: &Vec<Passport>) -> Answer {
+        let valid_passports = passports.iter().filter(|p| p.is_valid()).count();
+
+        Answer::from(valid_passports)
+    }
+
+    fn part2(&self, passports: &Vec<Passport>) -> Option<Answer> {
+        let valid_passports = passports.iter().filter(|p| p.is_valid_part2()).count();
+
+        Some(Answer::from(valid_passports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+    #[test]
+    fn test_read_passports_line_endings() {
+        for input in [EXAMPLE.to_string(), EXAMPLE.replace('\n', "\r\n")].iter() {
+            let passports = read_passports(input).unwrap();
+
+            assert_eq!(passports.len(), 4);
+            assert_eq!(passports.iter().filter(|p| p.is_valid()).count(), 2);
+        }
+    }
+
+    #[test]
+    fn test_read_passports_invalid_field() {
+        assert!(matches!(
+            read_passports("ecl:gry\n\npid:860033327 eyr2020"),
+            Err(Error::Parse {
+                line: 3,
+                column: 15,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::utils::parse_lines;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+fn parse_ticket_row(line: &str, s: &str) -> Result<String, Error> {
+    s.char_indices()
+        .map(|(idx, c)| {
+            if c == 'F' {
+                Ok('0')
+            } else if c == 'B' {
+                Ok('1')
+            } else {
+                Err(Error::parse(
+                    line,
+                    &s[idx..idx + c.len_utf8()],
+                    "invalid row character",
+                ))
+            }
+        })
+        .collect()
+}
+
+fn parse_ticket_col(line: &str, s: &str) -> Result<String, Error> {
+    s.char_indices()
+        .map(|(idx, c)| {
+            if c == 'R' {
+                Ok('1')
+            } else if c == 'L' {
+                Ok('0')
+            } else {
+                Err(Error::parse(
+                    line,
+                    &s[idx..idx + c.len_utf8()],
+                    "invalid column character",
+                ))
+            }
+        })
+        .collect()
+}
+
+fn convert_binary_string_to_number(binary_str: String) -> i64 {
+    let mut pow = 1;
+    let mut accumm = 0;
+
+    binary_str.chars().rev().for_each(|c| {
+        if c == '1' {
+            accumm += pow;
+        }
+        pow *= 2;
+    });
+
+    accumm
+}
+
+fn parse_ticket(s: &str) -> Result<i64, Error> {
+    if s.len() != 10 || !s.is_char_boundary(7) {
+        return Err(Error::parse(s, s, "expected 7 row and 3 column characters"));
+    }
+    let (row, col) = s.split_at(7);
+
+    let row_value = convert_binary_string_to_number(parse_ticket_row(s, row)?);
+    let col_value = convert_binary_string_to_number(parse_ticket_col(s, col)?);
+    let seat_value = (row_value * 8) + col_value;
+
+    trace!(
+        "row: {} parsed_row: {} col: {} parsed_column: {} seat_value: {}",
+        row,
+        row_value,
+        col,
+        col_value,
+        seat_value
+    );
+
+    Ok(seat_value)
+}
+
+fn find_missing_seat(seat_ids: &[i64]) -> i64 {
+    let seat_ids_set: HashSet<i64> = HashSet::from_iter(seat_ids.iter().cloned());
+
+    let min_seat_id = *seat_ids.iter().min().unwrap();
+    let max_seat_id = *seat_ids.iter().max().unwrap();
+
+    for seat_id in min_seat_id..max_seat_id {
+        if !seat_ids_set.contains(&seat_id)
+            && seat_ids_set.contains(&(seat_id - 1))
+            && seat_ids_set.contains(&(seat_id + 1))
+        {
+            return seat_id;
+        }
+    }
+    panic!("Seat id not found!");
+}
+
+/// `size` boarding passes (3 to 1000) for consecutive seats, but for one missing in between.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(3, 1000) as u64;
+
+    // one pass per seat from `first` to `first + count`, but for the missing one
+    let first = rng.range(0, 1023 - count);
+    let missing = rng.range(first + 1, first + count - 1);
+    let mut seats: Vec<u64> = (first..=first + count).filter(|&s| s != missing).collect();
+    rng.shuffle(&mut seats);
+
+    let mut input = String::new();
+    for seat in seats {
+        let (row, column) = (seat / 8, seat % 8);
+        for bit in (0..7).rev() {
+            input.push(if row >> bit & 1 == 1 { 'B' } else { 'F' });
+        }
+        for bit in (0..3).rev() {
+            input.push(if column >> bit & 1 == 1 { 'R' } else { 'L' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, Error> {
+        parse_lines(input, parse_ticket)
+    }
+
+    fn summary(&self, seat_ids: &Vec<i64>) -> Summary {
+        Summary::new()
+            .fact("boarding passes", seat_ids.len())
+            .range("seat ids", seat_ids.iter())
+    }
+
+    fn part1(&self, seat_ids: &Vec<i64>) -> Answer {
+        let best_seat = *seat_ids.iter().max().unwrap();
+
+        Answer::from(best_seat)
+    }
+
+    fn part2(&self, seat_ids: &Vec<i64>) -> Option<Answer> {
+        Some(Answer::from(find_missing_seat(seat_ids)))
+    }
+}
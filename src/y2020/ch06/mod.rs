@@ -0,0 +1,93 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_blocks, parse_lines};
+use std::collections::HashSet;
+
+fn group_answers_anyone(group: &str) -> usize {
+    group
+        .lines()
+        .flat_map(|l| l.chars())
+        .collect::<HashSet<char>>()
+        .len()
+}
+
+fn group_answers_everyone(group: &str) -> usize {
+    let answers: Vec<HashSet<char>> = group
+        .lines()
+        .map(|l| l.chars().collect::<HashSet<char>>())
+        .collect();
+
+    let smallest_set = answers
+        .iter()
+        .min_by(|&s1, &s2| s1.len().cmp(&s2.len()))
+        .unwrap();
+
+    smallest_set
+        .iter()
+        .filter(|c| answers.iter().all(|s| s.contains(c)))
+        .count()
+}
+
+/// `size` groups of 1 to 5 people, each answering yes to at least one question.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut groups = Vec::with_capacity(size);
+    for _ in 0..size {
+        // people of a group tend to give the same answers
+        let common: Vec<bool> = (0..26).map(|_| rng.chance(30)).collect();
+
+        let people = rng.range(1, 5);
+        let mut group = Vec::new();
+        for _ in 0..people {
+            let mut answers: String = (b'a'..=b'z')
+                .zip(&common)
+                .filter(|&(_, &common)| rng.chance(if common { 80 } else { 10 }))
+                .map(|(question, _)| char::from(question))
+                .collect();
+            if answers.is_empty() {
+                answers.push(rng.letter());
+            }
+            group.push(answers);
+        }
+        groups.push(group.join("\n"));
+    }
+
+    groups.join("\n\n") + "\n"
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, Error> {
+        parse_blocks(input, |group| {
+            parse_lines(group, |line| {
+                match line.find(|c: char| !c.is_ascii_lowercase()) {
+                    Some(idx) => Err(Error::parse(line, &line[idx..], "invalid answer")),
+                    None => Ok(()),
+                }
+            })?;
+            Ok(String::from(group))
+        })
+    }
+
+    fn summary(&self, groups: &Vec<String>) -> Summary {
+        Summary::new()
+            .fact("groups", groups.len())
+            .range("people", groups.iter().map(|group| group.lines().count()))
+    }
+
+    fn part1(&self, groups: &Vec<String>) -> Answer {
+        let answers: usize = groups.iter().map(|g| group_answers_anyone(g)).sum();
+
+        Answer::from(answers)
+    }
+
+    fn part2(&self, groups: &Vec<String>) -> Option<Answer> {
+        let answers: usize = groups.iter().map(|g| group_answers_everyone(g)).sum();
+
+        Some(Answer::from(answers))
+    }
+}
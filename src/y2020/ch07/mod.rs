@@ -0,0 +1,190 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::parse::{self, Cursor};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_lines;
+use std::collections::HashMap;
+
+fn parse_rhs_bag(cursor: &mut Cursor) -> Result<(i32, String), Error> {
+    let count = cursor.integer()?;
+    cursor.tag(" ")?;
+    let name = cursor.until(" bag")?;
+    cursor.try_tag("s");
+    Ok((count, name.to_string()))
+}
+
+fn parse_line(line: &str) -> Result<(String, Vec<(i32, String)>), Error> {
+    parse::line(line, |cursor| {
+        let lhs = cursor.until(" bags contain ")?.to_string();
+        let right_bags = if cursor.try_tag("no other bags") {
+            Vec::new()
+        } else {
+            cursor.separated(", ", parse_rhs_bag)?
+        };
+        cursor.try_tag(".");
+
+        Ok((lhs, right_bags))
+    })
+}
+
+fn parse_rules(input: &str) -> Result<HashMap<String, Vec<(i32, String)>>, Error> {
+    Ok(parse_lines(input, parse_line)?.into_iter().collect())
+}
+
+fn dfs_dumb(current: &str, target: &str, rules: &HashMap<String, Vec<(i32, String)>>) -> bool {
+    if current == target {
+        return true;
+    }
+
+    rules
+        .get(current)
+        .unwrap()
+        .iter()
+        .any(|r: &(i32, String)| dfs_dumb(&r.1, target, rules))
+}
+
+fn count_bags(current: String, rules: &HashMap<String, Vec<(i32, String)>>) -> i32 {
+    rules
+        .get(&current)
+        .unwrap()
+        .iter()
+        .map(|(c, b)| c * (1 + count_bags(String::from(b), rules)))
+        .sum()
+}
+
+const ADJECTIVES: [&str; 20] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dusty", "pastel",
+];
+
+const COLORS: [&str; 20] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "cyan", "fuchsia",
+    "gold", "gray", "green", "indigo", "lavender", "lime", "maroon", "olive", "orange", "plum",
+];
+
+/// `size` bag rules (at least 2), one of them for the shiny gold bag.
+///
+/// Bags are spread over a few levels and only contain bags of deeper levels, which keeps the
+/// number of bags inside the shiny gold one, halfway down, small. Rules are drawn from the
+/// deepest bags up, so a bag above the shiny gold one often holds it or a bag known to, and
+/// most of them lead down to it like in the puzzle inputs.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    const LEVELS: usize = 6;
+    let count = size.max(2);
+
+    // more bags than pairs of words tell the adjectives apart with a number
+    let mut names: Vec<String> = (0..)
+        .map(|idx: usize| {
+            let adjective = ADJECTIVES[idx % ADJECTIVES.len()];
+            let color = COLORS[idx / ADJECTIVES.len() % COLORS.len()];
+            match idx / (ADJECTIVES.len() * COLORS.len()) {
+                0 => format!("{} {}", adjective, color),
+                round => format!("{}{} {}", adjective, round, color),
+            }
+        })
+        .filter(|name| name != "shiny gold")
+        .take(count - 1)
+        .collect();
+    rng.shuffle(&mut names);
+    let gold = count / 2;
+    names.insert(gold, String::from("shiny gold"));
+
+    let level = |idx: usize| idx * LEVELS / count;
+    // the shiny gold bag, and the bags holding it directly or not
+    let mut holding = vec![gold];
+    let mut rules = Vec::with_capacity(count);
+    for (idx, name) in names.iter().enumerate().rev() {
+        let deeper = (idx + 1..count).find(|&i| level(i) > level(idx));
+        let mut bags: Vec<usize> = match deeper {
+            Some(first) if !rng.chance(10) => {
+                let bags = rng.range(1, 4).min((count - first) as u64) as usize;
+                rng.distinct(first as u64, count as u64 - 1, bags)
+                    .into_iter()
+                    .map(|bag| bag as usize)
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        if let Some(first) = deeper {
+            let leads_to_gold: Vec<usize> = holding
+                .iter()
+                .copied()
+                .filter(|&bag| bag >= first)
+                .collect();
+            if !leads_to_gold.is_empty() && rng.chance(60) {
+                let bag = *rng.choose(&leads_to_gold);
+                if !bags.contains(&bag) {
+                    bags.push(bag);
+                }
+            }
+        }
+        if bags.iter().any(|bag| holding.contains(bag)) {
+            holding.push(idx);
+        }
+
+        let contents: Vec<String> = bags
+            .into_iter()
+            .map(|bag| match rng.range(1, 5) {
+                1 => format!("1 {} bag", names[bag]),
+                n => format!("{} {} bags", n, names[bag]),
+            })
+            .collect();
+        rules.push(match contents.len() {
+            0 => format!("{} bags contain no other bags.\n", name),
+            _ => format!("{} bags contain {}.\n", name, contents.join(", ")),
+        });
+    }
+    rng.shuffle(&mut rules);
+
+    rules.concat()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Vec<(i32, String)>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_rules(input)
+    }
+
+    fn summary(&self, rules: &Self::Input) -> Summary {
+        let contents = rules.values().flatten();
+        Summary::new()
+            .fact("bag colors", rules.len())
+            .range("colors inside", rules.values().map(Vec::len))
+            .range("quantities", contents.map(|(count, _)| *count))
+    }
+
+    fn part1(&self, rules: &Self::Input) -> Answer {
+        let target = "shiny gold";
+
+        let count = rules
+            .keys()
+            .filter(|r| *r != target && dfs_dumb(r, target, rules))
+            .count();
+
+        Answer::from(count)
+    }
+
+    fn part2(&self, rules: &Self::Input) -> Option<Answer> {
+        let count = count_bags(String::from("shiny gold"), rules);
+
+        Some(Answer::from(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_bags_lead_to_shiny_gold() {
+        for seed in 0..16 {
+            let input = generate(&mut Rng::new(seed), 600);
+            let rules = parse_rules(&input).unwrap();
+            assert_ne!(Day.part1(&rules), Answer::from(0usize), "seed {}", seed);
+        }
+    }
+}
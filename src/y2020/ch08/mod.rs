@@ -0,0 +1,235 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::utils::parse_lines;
+use std::collections::HashSet;
+
+/// An instruction of the handheld game console.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+/// Parses a single instruction like `acc +3`.
+pub fn parse_instruction(line: &str) -> Result<Instruction, Error> {
+    parse::line(line, |cursor| {
+        let op = cursor.word()?;
+        cursor.tag(" ")?;
+        let param = cursor.integer()?;
+
+        match op {
+            "nop" => Ok(Instruction::Nop(param)),
+            "acc" => Ok(Instruction::Acc(param)),
+            "jmp" => Ok(Instruction::Jmp(param)),
+            _ => Err(cursor.error(op, "unknown operation")),
+        }
+    })
+}
+
+/// Parses a program, one instruction per line.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, Error> {
+    parse_lines(input, parse_instruction)
+}
+
+/// How a program stopped, along with the accumulator value at that point.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Termination {
+    /// Jumped before the first instruction.
+    Failed,
+    /// About to run an instruction for the second time.
+    InfiniteLoop(i32),
+    /// Ran past the last instruction.
+    Normal(i32),
+}
+
+/// Runs the program until it terminates or starts looping.
+pub fn compute_accumulator(instructions: &[Instruction]) -> Termination {
+    let mut accum = 0;
+    let mut visited: HashSet<i32> = HashSet::new();
+    let mut it: i32 = 0;
+
+    while it < instructions.len() as i32 {
+        if it < 0 {
+            return Termination::Failed;
+        }
+
+        let instr = &instructions[it as usize];
+
+        trace!("on step {}: {:?}", it, instr);
+
+        if visited.contains(&it) {
+            return Termination::InfiniteLoop(accum);
+        }
+        visited.insert(it);
+
+        match instr {
+            Instruction::Nop(_) => it += 1,
+            Instruction::Acc(num) => {
+                accum += num;
+                it += 1
+            }
+            Instruction::Jmp(num) => it += num,
+        }
+    }
+
+    Termination::Normal(accum)
+}
+
+fn get_alternate_instruction(instr: &Instruction) -> Instruction {
+    match instr {
+        Instruction::Nop(num) => Instruction::Jmp(*num),
+        Instruction::Jmp(num) => Instruction::Nop(*num),
+        Instruction::Acc(num) => Instruction::Acc(*num),
+    }
+}
+
+/// Repairs the program by swapping a single `jmp` and `nop` so that it terminates normally, and
+/// returns the final accumulator value.
+pub fn search_correct_result(instructions: &mut [Instruction]) -> i32 {
+    let len = instructions.len();
+
+    for idx in 0..len {
+        let current_instruction = &instructions[idx];
+
+        let alternate_instruction = get_alternate_instruction(current_instruction);
+
+        instructions[idx] = alternate_instruction;
+
+        if let Termination::Normal(accum) = compute_accumulator(instructions) {
+            return accum;
+        }
+
+        instructions[idx] = get_alternate_instruction(&alternate_instruction);
+    }
+    panic!("No solution found!");
+}
+
+/// A program of `size` instructions (at least 2) that loops forever, but runs to its end once
+/// the `jmp` closing its loop is changed to a `nop`.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let len = size.max(2);
+
+    // a program that only jumps forward, and so ends
+    let mut instructions: Vec<Instruction> = (0..len)
+        .map(|idx| match rng.range(0, 9) {
+            0..=4 => Instruction::Acc(rng.range(0, 100) as i32 - 50),
+            5 | 6 => Instruction::Nop(rng.range(0, 100) as i32 - 50),
+            _ => Instruction::Jmp(rng.range(1, 10.min(len - idx) as u64) as i32),
+        })
+        .collect();
+    if let Instruction::Jmp(_) = instructions[0] {
+        instructions[0] = Instruction::Acc(rng.range(0, 100) as i32 - 50);
+    }
+
+    let mut executed = Vec::new();
+    let mut idx = 0;
+    while idx < len {
+        executed.push(idx);
+        idx += match instructions[idx] {
+            Instruction::Jmp(offset) => offset as usize,
+            _ => 1,
+        };
+    }
+
+    // jumping back from an instruction that is not a jump closes a loop, a `nop` reopens it
+    let candidates: Vec<usize> = executed
+        .iter()
+        .copied()
+        .filter(|&idx| !matches!(instructions[idx], Instruction::Jmp(_)))
+        .collect();
+    let from = *rng.choose(&candidates);
+    let to = *rng.choose(&executed[..=executed.iter().position(|&idx| idx == from).unwrap()]);
+    instructions[from] = Instruction::Jmp(to as i32 - from as i32);
+
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Nop(arg) => format!("nop {:+}\n", arg),
+            Instruction::Acc(arg) => format!("acc {:+}\n", arg),
+            Instruction::Jmp(arg) => format!("jmp {:+}\n", arg),
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        parse_instructions(input)
+    }
+
+    fn summary(&self, instructions: &Vec<Instruction>) -> Summary {
+        let count = |f: fn(&Instruction) -> bool| instructions.iter().filter(|i| f(i)).count();
+        Summary::new()
+            .fact("instructions", instructions.len())
+            .fact("acc", count(|i| matches!(i, Instruction::Acc(_))))
+            .fact("jmp", count(|i| matches!(i, Instruction::Jmp(_))))
+            .fact("nop", count(|i| matches!(i, Instruction::Nop(_))))
+            .range(
+                "arguments",
+                instructions.iter().map(|i| match i {
+                    Instruction::Nop(n) | Instruction::Acc(n) | Instruction::Jmp(n) => *n,
+                }),
+            )
+    }
+
+    fn part1(&self, instructions: &Vec<Instruction>) -> Answer {
+        match compute_accumulator(instructions) {
+            Termination::InfiniteLoop(accum) => Answer::from(accum),
+            result => panic!("program did not loop: {:?}", result),
+        }
+    }
+
+    fn part2(&self, instructions: &Vec<Instruction>) -> Option<Answer> {
+        let mut instructions = instructions.clone();
+
+        let accum = search_correct_result(&mut instructions);
+
+        Some(Answer::from(accum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_accumulator() {
+        let mut instructions = parse_instructions(
+            "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6",
+        )
+        .unwrap();
+
+        assert_eq!(
+            compute_accumulator(&instructions),
+            Termination::InfiniteLoop(5)
+        );
+        assert_eq!(search_correct_result(&mut instructions), 8);
+        assert_eq!(
+            compute_accumulator(&[Instruction::Jmp(-1)]),
+            Termination::Failed
+        );
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(parse_instruction("acc -99"), Ok(Instruction::Acc(-99)));
+        assert_eq!(parse_instruction("jmp +4"), Ok(Instruction::Jmp(4)));
+        assert_eq!(
+            parse_instruction("mul +4").unwrap_err(),
+            Error::parse("mul +4", "mul", "unknown operation")
+        );
+        assert_eq!(
+            parse_instruction("nop +x").unwrap_err(),
+            Error::parse("nop +x", "+x", "invalid number")
+        );
+        assert!(parse_instruction("nop").is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::check::Summary;
+use crate::debug;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, parse_number};
+
+fn is_valid(numbers: &[u64], current_position: usize, k: usize) -> bool {
+    let num = numbers[current_position];
+
+    for y in current_position - k..current_position {
+        for z in y + 1..current_position {
+            if numbers[y] + numbers[z] == num {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn find_first_invalid(numbers: &[u64], k: usize) -> u64 {
+    for x in k..numbers.len() {
+        if !is_valid(numbers, x, k) {
+            return numbers[x];
+        }
+    }
+    panic!("no invalid number found");
+}
+
+fn read_numbers(input: &str) -> Result<Vec<u64>, Error> {
+    parse_lines(input, |l| parse_number(l, l))
+}
+
+fn find_weakness(numbers: &[u64], sum: u64) -> (usize, usize) {
+    let mut start = 0;
+    let mut end = 0;
+    let mut accumulator = 0;
+
+    while end < numbers.len() && start <= end {
+        if accumulator == sum {
+            return (start, end);
+        } else if accumulator < sum {
+            accumulator += numbers[end];
+            end += 1;
+        } else {
+            accumulator -= numbers[start];
+            start += 1;
+        }
+    }
+
+    panic!("no weakness found");
+}
+
+/// Length of the preamble in the puzzle input; the examples use a shorter one.
+pub const PREAMBLE: usize = 25;
+
+/// `size` numbers (at least 27): after the preamble, each one is the sum of two of the
+/// [`PREAMBLE`] numbers before it, up to one that is the sum of a contiguous range of earlier
+/// numbers instead. The numbers after that one are random.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    const MAX_SUM: u64 = 1_000_000_000_000_000;
+    let count = size.max(PREAMBLE + 2);
+
+    let mut numbers = rng.distinct(1, 50, PREAMBLE);
+    let invalid_at = rng.range(PREAMBLE as u64, (count - 1).min(500) as u64) as usize;
+    while numbers.len() < invalid_at {
+        let window = &numbers[numbers.len() - PREAMBLE..];
+        let mut terms = rng.distinct(0, PREAMBLE as u64 - 1, 2);
+        // the sums only grow slowly when they are the sums of the smallest numbers
+        if terms.iter().map(|&t| window[t as usize]).sum::<u64>() > MAX_SUM {
+            let mut sorted: Vec<u64> = (0..PREAMBLE as u64).collect();
+            sorted.sort_by_key(|&t| window[t as usize]);
+            terms = sorted[..2].to_vec();
+        }
+        let sum = terms.iter().map(|&t| window[t as usize]).sum();
+        numbers.push(sum);
+    }
+
+    loop {
+        let start = rng.range(0, invalid_at as u64 - 2) as usize;
+        let end = rng.range(start as u64 + 2, (start + 17).min(invalid_at) as u64) as usize;
+        let sum: u64 = numbers[start..end].iter().sum();
+        if numbers.contains(&sum) {
+            continue;
+        }
+
+        numbers.push(sum);
+        if !is_valid(&numbers, invalid_at, PREAMBLE) {
+            break;
+        }
+        numbers.pop();
+    }
+
+    let largest = *numbers.iter().max().unwrap();
+    while numbers.len() < count {
+        numbers.push(rng.range(1, largest));
+    }
+
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+pub struct Day {
+    pub preamble: usize,
+}
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, Error> {
+        read_numbers(input)
+    }
+
+    fn summary(&self, numbers: &Vec<u64>) -> Summary {
+        Summary::new()
+            .fact("numbers", numbers.len())
+            .fact("preamble", self.preamble)
+            .range("values", numbers.iter())
+    }
+
+    fn part1(&self, numbers: &Vec<u64>) -> Answer {
+        let first_incorrect = find_first_invalid(numbers, self.preamble);
+
+        Answer::from(first_incorrect)
+    }
+
+    fn part2(&self, numbers: &Vec<u64>) -> Option<Answer> {
+        let first_incorrect = find_first_invalid(numbers, self.preamble);
+        debug!("first incorrect: {}", first_incorrect);
+
+        let weakness_bounds = find_weakness(numbers, first_incorrect);
+        let weakness_range = &numbers[weakness_bounds.0..weakness_bounds.1];
+
+        let smallest = weakness_range.iter().min().unwrap();
+        let highest = weakness_range.iter().max().unwrap();
+
+        let weakness = smallest + highest;
+
+        Some(Answer::from(weakness))
+    }
+}
@@ -0,0 +1,110 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, parse_number};
+use std::collections::HashMap;
+
+fn get_possibilities(possibilities: &HashMap<u64, u64>, number: u64) -> u64 {
+    let mut accumulator = 0;
+
+    if number >= 1 {
+        accumulator += possibilities.get(&(number - 1)).unwrap_or(&0);
+    }
+    if number >= 2 {
+        accumulator += possibilities.get(&(number - 2)).unwrap_or(&0);
+    }
+    if number >= 3 {
+        accumulator += possibilities.get(&(number - 3)).unwrap_or(&0);
+    }
+
+    accumulator
+}
+
+/// `size` adapters (at least 1), each 1 or 3 jolts above another one.
+///
+/// As in the puzzle input, adapters come in runs 1 jolt apart, separated by gaps of 3. Runs
+/// get shorter once the number of ways to chain the adapters would no longer fit in 63 bits.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    // ways to cross a run of n steps of 1 jolt, in steps of 1 to 3 jolts
+    const WAYS: [u64; 5] = [1, 1, 2, 4, 7];
+
+    let mut adapters = Vec::with_capacity(size);
+    let mut joltage = 0;
+    let mut arrangements: u64 = 1;
+    while adapters.len() < size.max(1) {
+        let mut run = rng.range(0, 4) as usize;
+        while arrangements.saturating_mul(WAYS[run]) > i64::MAX as u64 {
+            run -= 1;
+        }
+        arrangements *= WAYS[run];
+
+        for _ in 0..run {
+            joltage += 1;
+            adapters.push(joltage);
+        }
+        joltage += 3;
+        adapters.push(joltage);
+    }
+    adapters.truncate(size.max(1));
+    rng.shuffle(&mut adapters);
+
+    adapters.iter().map(|a| format!("{}\n", a)).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, Error> {
+        let mut numbers: Vec<u64> = parse_lines(input, |l| parse_number(l, l))?;
+
+        numbers.sort();
+
+        Ok(numbers)
+    }
+
+    fn summary(&self, numbers: &Vec<u64>) -> Summary {
+        Summary::new()
+            .fact("adapters", numbers.len())
+            .range("joltages", numbers.iter())
+    }
+
+    fn part1(&self, numbers: &Vec<u64>) -> Answer {
+        let mut plus_ones = 0;
+        let mut plus_threes = 0;
+        let mut current = 0;
+
+        for number in numbers {
+            if *number > current + 3 {
+                panic!("Could not reach {} from {}", number, current);
+            }
+            let diff = *number - current;
+
+            if diff == 1 {
+                plus_ones += 1;
+            } else if diff == 3 {
+                plus_threes += 1;
+            }
+
+            current = *number;
+        }
+
+        Answer::from(plus_ones * (plus_threes + 1))
+    }
+
+    fn part2(&self, numbers: &Vec<u64>) -> Option<Answer> {
+        let mut possibilities: HashMap<u64, u64> = HashMap::new();
+        possibilities.insert(0, 1);
+
+        numbers.iter().for_each(|num| {
+            let accum = get_possibilities(&possibilities, *num);
+            possibilities.insert(*num, accum);
+        });
+
+        let final_possibilities = get_possibilities(&possibilities, *numbers.last().unwrap());
+
+        Some(Answer::from(final_possibilities))
+    }
+}
@@ -0,0 +1,273 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::export::Color;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::progress;
+use crate::solution::{Answer, Solution};
+
+mod room {
+    use crate::automaton::{Automaton, FiniteTopology, Rule, Topology};
+    use crate::check::Summary;
+    use crate::debug;
+    use crate::error::Error;
+    use crate::export::Color;
+    use crate::grid::{Grid, NEIGHBORS8};
+    use crate::progress::Progress;
+    use std::collections::HashSet;
+    use std::fmt;
+    use std::fmt::Formatter;
+
+    #[derive(PartialEq, Copy, Clone)]
+    enum Tile {
+        Floor,
+        Empty,
+        Occupied,
+    }
+
+    impl Tile {
+        fn from_char(c: char) -> Option<Tile> {
+            match c {
+                '.' => Some(Tile::Floor),
+                'L' => Some(Tile::Empty),
+                '#' => Some(Tile::Occupied),
+                _ => None,
+            }
+        }
+
+        fn to_char(self) -> char {
+            match self {
+                Tile::Floor => '.',
+                Tile::Empty => 'L',
+                Tile::Occupied => '#',
+            }
+        }
+
+        fn color(self) -> Color {
+            match self {
+                Tile::Floor => Color(60, 60, 60),
+                Tile::Empty => Color(180, 180, 180),
+                Tile::Occupied => Color(255, 140, 0),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct Room {
+        map: Grid<Tile>,
+    }
+
+    #[derive(Copy, Clone)]
+    pub(crate) enum NearbyTileStrategy {
+        Adjacent,
+        Visible,
+    }
+
+    /// The seats of a room, each one neighboring the seats it takes into account.
+    struct Seats<'a> {
+        map: &'a Grid<Tile>,
+        strategy: NearbyTileStrategy,
+    }
+
+    impl Topology for Seats<'_> {
+        type Cell = (usize, usize);
+
+        fn neighbors(&self, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+            match self.strategy {
+                NearbyTileStrategy::Adjacent => self
+                    .map
+                    .neighbors8(x, y)
+                    .filter(|&p| self.map[p] != Tile::Floor)
+                    .collect(),
+                NearbyTileStrategy::Visible => NEIGHBORS8
+                    .iter()
+                    .filter_map(|&direction| {
+                        self.map
+                            .ray(x, y, direction)
+                            .find(|&p| self.map[p] != Tile::Floor)
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl FiniteTopology for Seats<'_> {
+        fn cells(&self) -> Vec<(usize, usize)> {
+            self.map
+                .iter()
+                .filter(|(_, &tile)| tile != Tile::Floor)
+                .map(|(p, _)| p)
+                .collect()
+        }
+    }
+
+    impl Room {
+        pub(crate) fn summary(&self) -> Summary {
+            Summary::new()
+                .fact("rows", self.map.height())
+                .fact("columns", self.map.width())
+                .fact("seats", self.map.count(|&tile| tile != Tile::Floor))
+        }
+
+        pub(crate) fn from_lines(str: &str) -> Result<Room, Error> {
+            let map = Grid::parse(str, Tile::from_char)?;
+
+            Ok(Room { map })
+        }
+
+        fn automaton(&self, strategy: NearbyTileStrategy) -> Automaton<Seats<'_>> {
+            // an empty seat gets occupied if none of the seats around is, an occupied one is
+            // left if too many of the seats around are occupied
+            let tolerance = match strategy {
+                NearbyTileStrategy::Adjacent => 4,
+                NearbyTileStrategy::Visible => 5,
+            };
+            let survival: Vec<usize> = (0..tolerance).collect();
+
+            let seats = Seats {
+                map: &self.map,
+                strategy,
+            };
+            let occupied = self
+                .map
+                .iter()
+                .filter(|(_, &tile)| tile == Tile::Occupied)
+                .map(|(p, _)| p);
+            Automaton::dense(seats, Rule::new(&[0], &survival), occupied)
+        }
+
+        /// Whether no seat changes any more after at most `rounds` rounds. Some rooms never
+        /// settle, with seats taken and left in turn.
+        pub(crate) fn stabilizes_within(
+            &self,
+            strategy: NearbyTileStrategy,
+            rounds: usize,
+        ) -> bool {
+            let mut automaton = self.automaton(strategy);
+            (0..rounds).any(|_| !automaton.step())
+        }
+
+        /// Lets people move until no seat changes, reporting the rounds to `progress`.
+        pub(crate) fn simulate(
+            &mut self,
+            strategy: NearbyTileStrategy,
+            progress: &mut dyn Progress,
+        ) {
+            let mut automaton = self.automaton(strategy);
+
+            let iterations =
+                automaton.run_until_stable(|seats| progress.update(seats.generation(), None));
+            progress.finish();
+            debug!("Stabilized after {} iterations", iterations);
+
+            self.map = self.seated(&automaton.live_cells());
+        }
+
+        /// The room before anyone moves and after each round, until no seat changes.
+        pub(crate) fn render_rounds(&self, strategy: NearbyTileStrategy) -> Vec<Grid<Color>> {
+            let mut frames = vec![self.map.map(|&tile| tile.color())];
+
+            let mut automaton = self.automaton(strategy);
+            automaton.run_until_stable(|seats| {
+                frames.push(self.seated(&seats.live_cells()).map(|&tile| tile.color()))
+            });
+            // the last round changed nothing
+            frames.pop();
+
+            frames
+        }
+
+        /// The map with exactly the `occupied` seats taken.
+        fn seated(&self, occupied: &[(usize, usize)]) -> Grid<Tile> {
+            let occupied: HashSet<&(usize, usize)> = occupied.iter().collect();
+            Grid::from_fn(self.map.width(), self.map.height(), |x, y| {
+                match self.map[(x, y)] {
+                    Tile::Floor => Tile::Floor,
+                    _ if occupied.contains(&(x, y)) => Tile::Occupied,
+                    _ => Tile::Empty,
+                }
+            })
+        }
+
+        pub(crate) fn count_occupied_seats(&self) -> usize {
+            self.map.count(|&t| t == Tile::Occupied)
+        }
+    }
+
+    impl fmt::Display for Room {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.map.render(|tile| tile.to_char()))
+        }
+    }
+}
+
+/// A waiting area of `size` rows (at least 1) of 90 positions, three in four of them seats.
+/// Areas where people would keep moving forever are drawn again.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            input.extend((0..90).map(|_| if rng.chance(75) { 'L' } else { '.' }));
+            input.push('\n');
+        }
+
+        let room = room::Room::from_lines(&input).unwrap();
+        if [
+            room::NearbyTileStrategy::Adjacent,
+            room::NearbyTileStrategy::Visible,
+        ]
+        .iter()
+        .all(|&strategy| room.stabilizes_within(strategy, 1000))
+        {
+            return input;
+        }
+    }
+}
+
+/// The waiting area before anyone moves and after each round, with the seats around taken into
+/// account as in part `part`.
+pub(crate) fn frames(input: &str, part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    let room = room::Room::from_lines(input)?;
+    let strategy = match part {
+        1 => room::NearbyTileStrategy::Adjacent,
+        _ => room::NearbyTileStrategy::Visible,
+    };
+
+    Ok(room.render_rounds(strategy))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = room::Room;
+
+    fn parse(&self, input: &str) -> Result<room::Room, Error> {
+        room::Room::from_lines(input)
+    }
+
+    fn summary(&self, room: &room::Room) -> Summary {
+        room.summary()
+    }
+
+    fn part1(&self, room: &room::Room) -> Answer {
+        let mut room = room.clone();
+
+        room.simulate(
+            room::NearbyTileStrategy::Adjacent,
+            progress::reporter("day 11").as_mut(),
+        );
+
+        Answer::from(room.count_occupied_seats())
+    }
+
+    fn part2(&self, room: &room::Room) -> Option<Answer> {
+        let mut room = room.clone();
+
+        room.simulate(
+            room::NearbyTileStrategy::Visible,
+            progress::reporter("day 11").as_mut(),
+        );
+
+        Some(Answer::from(room.count_occupied_seats()))
+    }
+}
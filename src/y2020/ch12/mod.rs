@@ -0,0 +1,408 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::export::Color;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_lines;
+
+mod navigation {
+    use crate::error::Error;
+    use crate::utils::parse_number;
+
+    #[derive(Copy, Clone)]
+    pub enum Direction {
+        South,
+        North,
+        West,
+        East,
+        Forward,
+    }
+
+    #[derive(Copy, Clone)]
+    pub enum Rotation {
+        Right,
+        Left,
+    }
+
+    #[derive(Copy, Clone)]
+    pub enum Step {
+        Direction(Direction),
+        Rotation(Rotation),
+    }
+
+    impl Step {
+        fn from_char(c: char) -> Option<Step> {
+            match c {
+                'N' => Some(Step::Direction(Direction::North)),
+                'S' => Some(Step::Direction(Direction::South)),
+                'E' => Some(Step::Direction(Direction::East)),
+                'W' => Some(Step::Direction(Direction::West)),
+                'F' => Some(Step::Direction(Direction::Forward)),
+                'L' => Some(Step::Rotation(Rotation::Left)),
+                'R' => Some(Step::Rotation(Rotation::Right)),
+                _ => None,
+            }
+        }
+    }
+
+    pub struct Instruction {
+        pub(crate) step: Step,
+        pub(crate) amount: i32,
+    }
+
+    impl Instruction {
+        pub(crate) fn from_str(line: &str) -> Result<Instruction, Error> {
+            let c = line
+                .chars()
+                .next()
+                .ok_or_else(|| Error::parse(line, line, "empty instruction"))?;
+            let (action, amount) = line.split_at(c.len_utf8());
+            let step =
+                Step::from_char(c).ok_or_else(|| Error::parse(line, action, "unknown action"))?;
+            let amount: i32 = parse_number(line, amount)?;
+
+            // the waypoint can only be rotated by a quarter, half or three quarters of a turn
+            if let Step::Rotation(_) = step {
+                if ![90, 180, 270].contains(&amount) {
+                    return Err(Error::parse(line, &line[1..], "unsupported rotation"));
+                }
+            }
+
+            Ok(Instruction { step, amount })
+        }
+    }
+
+    pub(crate) struct State {
+        east: i32,
+        north: i32,
+        direction_states: Vec<Direction>,
+        current_direction: usize,
+    }
+
+    impl State {
+        pub(crate) fn new() -> State {
+            State {
+                east: 0,
+                north: 0,
+                direction_states: vec![
+                    Direction::North,
+                    Direction::East,
+                    Direction::South,
+                    Direction::West,
+                ],
+                current_direction: 1,
+            }
+        }
+
+        fn current_direction(&self) -> Direction {
+            self.direction_states[self.current_direction]
+        }
+
+        fn rotate_left(&mut self, angles: i32) {
+            let position_change = angles / 90;
+            self.current_direction =
+                ((4 + self.current_direction as i32 - position_change) % 4) as usize;
+        }
+
+        fn rotate_right(&mut self, angles: i32) {
+            let position_change = angles / 90;
+            self.current_direction =
+                ((self.current_direction as i32 + position_change) % 4) as usize;
+        }
+
+        fn move_boat(&mut self, direction: Direction, distance: i32) {
+            match direction {
+                Direction::South => {
+                    self.north -= distance;
+                }
+                Direction::North => {
+                    self.north += distance;
+                }
+                Direction::West => {
+                    self.east -= distance;
+                }
+                Direction::East => {
+                    self.east += distance;
+                }
+                Direction::Forward => {
+                    self.move_boat(self.current_direction(), distance);
+                }
+            }
+        }
+
+        pub(crate) fn step(&mut self, instruction: &Instruction) {
+            match instruction.step {
+                Step::Direction(direction) => {
+                    self.move_boat(direction, instruction.amount);
+                }
+                Step::Rotation(rotation) => match rotation {
+                    Rotation::Right => {
+                        self.rotate_right(instruction.amount);
+                    }
+                    Rotation::Left => {
+                        self.rotate_left(instruction.amount);
+                    }
+                },
+            }
+        }
+
+        pub(crate) fn distance(&self) -> i32 {
+            self.east.abs() + self.north.abs()
+        }
+
+        /// East and north coordinates of the boat.
+        pub(crate) fn position(&self) -> (i32, i32) {
+            (self.east, self.north)
+        }
+    }
+}
+
+/// Calls `on_step` with the positions of the ship and of the waypoint, relative to the ship,
+/// after each instruction.
+fn navigate_with_waypoint<F>(instructions: &[navigation::Instruction], mut on_step: F) -> i32
+where
+    F: FnMut((i32, i32), (i32, i32)),
+{
+    let mut ship_east = 0;
+    let mut ship_north = 0;
+
+    let mut waypoint_east = 10;
+    let mut waypoint_north = 1;
+
+    for instruction in instructions {
+        match instruction.step {
+            navigation::Step::Direction(direction) => match direction {
+                navigation::Direction::South => {
+                    waypoint_north -= instruction.amount;
+                }
+                navigation::Direction::North => {
+                    waypoint_north += instruction.amount;
+                }
+                navigation::Direction::West => {
+                    waypoint_east -= instruction.amount;
+                }
+                navigation::Direction::East => {
+                    waypoint_east += instruction.amount;
+                }
+                navigation::Direction::Forward => {
+                    ship_east += instruction.amount * waypoint_east;
+                    ship_north += instruction.amount * waypoint_north;
+                }
+            },
+            navigation::Step::Rotation(rotation) => {
+                let amount = instruction.amount / 90;
+                let tmp_east = waypoint_east;
+                let tmp_north = waypoint_north;
+
+                match rotation {
+                    navigation::Rotation::Right => match amount {
+                        1 => {
+                            waypoint_east = tmp_north;
+                            waypoint_north = -tmp_east;
+                        }
+                        2 => {
+                            waypoint_east = -tmp_east;
+                            waypoint_north = -tmp_north;
+                        }
+                        3 => {
+                            waypoint_east = -tmp_north;
+                            waypoint_north = tmp_east;
+                        }
+                        _ => {
+                            panic!("unrecognized rotation {}", instruction.amount);
+                        }
+                    },
+                    navigation::Rotation::Left => match amount {
+                        1 => {
+                            waypoint_east = -tmp_north;
+                            waypoint_north = tmp_east;
+                        }
+                        2 => {
+                            waypoint_east = -tmp_east;
+                            waypoint_north = -tmp_north;
+                        }
+                        3 => {
+                            waypoint_east = tmp_north;
+                            waypoint_north = -tmp_east;
+                        }
+                        _ => {
+                            panic!("unrecognized rotation {}", instruction.amount);
+                        }
+                    },
+                }
+            }
+        }
+
+        on_step((ship_east, ship_north), (waypoint_east, waypoint_north));
+    }
+
+    ship_north.abs() + ship_east.abs()
+}
+
+/// Largest side of a route picture, in cells. Longer routes are scaled down to fit.
+const ROUTE_SIZE: i32 = 100;
+
+/// The route of the ship, one frame per instruction. The waypoint of part 2 is drawn as well.
+pub(crate) fn frames(input: &str, part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    let instructions = Day.parse(input)?;
+
+    // positions of the ship, and of the waypoint if any, from the start on
+    let mut steps = vec![((0, 0), if part == 1 { None } else { Some((10, 1)) })];
+    if part == 1 {
+        let mut state = navigation::State::new();
+        for instruction in &instructions {
+            state.step(instruction);
+            steps.push((state.position(), None));
+        }
+    } else {
+        navigate_with_waypoint(&instructions, |ship, (east, north)| {
+            steps.push((ship, Some((ship.0 + east, ship.1 + north))))
+        });
+    }
+
+    let points = steps
+        .iter()
+        .flat_map(|&(ship, waypoint)| std::iter::once(ship).chain(waypoint));
+    let (min_east, max_east, min_north, max_north) = points.fold(
+        (0, 0, 0, 0),
+        |(min_east, max_east, min_north, max_north), (east, north)| {
+            (
+                min_east.min(east),
+                max_east.max(east),
+                min_north.min(north),
+                max_north.max(north),
+            )
+        },
+    );
+    let scale = ((max_east - min_east).max(max_north - min_north) / ROUTE_SIZE) + 1;
+    // north is up
+    let cell = |(east, north): (i32, i32)| {
+        (
+            ((east - min_east) / scale) as usize,
+            ((max_north - north) / scale) as usize,
+        )
+    };
+
+    let mut trail = Grid::new(
+        cell((max_east, min_north)).0 + 1,
+        cell((max_east, min_north)).1 + 1,
+        Color(10, 30, 60),
+    );
+    let mut frames = Vec::with_capacity(steps.len());
+    for (idx, &(ship, waypoint)) in steps.iter().enumerate() {
+        if idx > 0 {
+            let (from, to) = (cell(steps[idx - 1].0), cell(ship));
+            let (dx, dy) = (
+                to.0 as isize - from.0 as isize,
+                to.1 as isize - from.1 as isize,
+            );
+            let length = dx.abs().max(dy.abs()).max(1);
+            for t in 0..=length {
+                let x = from.0 as isize + dx * t / length;
+                let y = from.1 as isize + dy * t / length;
+                trail[(x as usize, y as usize)] = Color(120, 160, 200);
+            }
+        }
+        trail[cell((0, 0))] = Color::WHITE;
+
+        let mut frame = trail.clone();
+        if let Some(waypoint) = waypoint {
+            frame[cell(waypoint)] = Color(80, 200, 80);
+        }
+        frame[cell(ship)] = Color(220, 50, 50);
+        frames.push(frame);
+    }
+    Ok(frames)
+}
+
+/// `size` navigation instructions.
+///
+/// Moves that would take the waypoint far from the ship bring it back instead, which keeps the
+/// ship's position small on long routes.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let (mut east, mut north): (i64, i64) = (10, 1);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let instruction = match rng.range(0, 99) {
+            0..=14 => {
+                let quarters = rng.range(1, 3);
+                let right = rng.chance(50);
+                for _ in 0..if right { quarters } else { 4 - quarters } {
+                    let (e, n) = (north, -east);
+                    east = e;
+                    north = n;
+                }
+                format!("{}{}", if right { 'R' } else { 'L' }, quarters * 90)
+            }
+            15..=59 => {
+                let amount = rng.range(1, 5) as i64;
+                let (coordinate, positive, negative) = if rng.chance(50) {
+                    (&mut east, 'E', 'W')
+                } else {
+                    (&mut north, 'N', 'S')
+                };
+                let forwards = match *coordinate {
+                    c if c > 20 => false,
+                    c if c < -20 => true,
+                    _ => rng.chance(50),
+                };
+                *coordinate += if forwards { amount } else { -amount };
+                format!("{}{}", if forwards { positive } else { negative }, amount)
+            }
+            _ => format!("F{}", rng.range(1, 100)),
+        };
+        input.push_str(&instruction);
+        input.push('\n');
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<navigation::Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, navigation::Instruction::from_str)
+    }
+
+    fn summary(&self, instructions: &Self::Input) -> Summary {
+        let count =
+            |f: fn(&navigation::Step) -> bool| instructions.iter().filter(|i| f(&i.step)).count();
+        Summary::new()
+            .fact("instructions", instructions.len())
+            .fact(
+                "forward",
+                count(|s| {
+                    matches!(
+                        s,
+                        navigation::Step::Direction(navigation::Direction::Forward)
+                    )
+                }),
+            )
+            .fact(
+                "turns",
+                count(|s| matches!(s, navigation::Step::Rotation(_))),
+            )
+            .range("amounts", instructions.iter().map(|i| i.amount))
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Answer {
+        let mut state = navigation::State::new();
+
+        for instruction in instructions {
+            state.step(instruction);
+        }
+
+        Answer::from(state.distance())
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(navigate_with_waypoint(
+            instructions,
+            |_, _| {},
+        )))
+    }
+}
@@ -0,0 +1,228 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_number;
+use crate::{debug, trace};
+
+fn find_earliest_time(time: i64, buses: &[(i64, i64)]) -> (i64, i64) {
+    let mut earliest_time = time;
+
+    loop {
+        if let Some((_, bus)) = buses.iter().find(|(_, b)| earliest_time % *b == 0) {
+            return (*bus, earliest_time);
+        }
+        earliest_time += 1;
+    }
+}
+
+fn read_input(input: &str) -> Result<(i64, Vec<(i64, i64)>), Error> {
+    let mut lines = input.lines();
+
+    let time_line = lines
+        .next()
+        .ok_or_else(|| Error::end_of_input(input, "missing timestamp"))?;
+    let time: i64 = parse_number(time_line, time_line)?;
+
+    let buses_line = lines
+        .next()
+        .ok_or_else(|| Error::end_of_input(input, "missing bus ids"))?;
+    let buses: Vec<(i64, i64)> = buses_line
+        .split(",")
+        .enumerate()
+        .filter(|(_idx, s)| *s != "x")
+        .map(|(idx, s)| match parse_number(buses_line, s)? {
+            bus if bus > 0 => Ok((idx as i64, bus)),
+            _ => Err(Error::parse(buses_line, s, "bus ids must be positive")),
+        })
+        .collect::<Result<_, Error>>()
+        .map_err(|e| e.offset_lines(1))?;
+
+    if buses.is_empty() {
+        return Err(Error::parse(buses_line, buses_line, "no buses in service").offset_lines(1));
+    }
+
+    Ok((time, buses))
+}
+
+fn is_valid(num: i64, buses: &[(i64, i64)]) -> bool {
+    buses.iter().all(|&(idx, b)| (num + idx) % b == 0)
+}
+
+/// Brute force version of [`solve_extended_euclidean`], trying every departure of the bus with
+/// the largest id in turn. Only usable when the product of the ids is small.
+pub(crate) fn find_time(buses: &[(i64, i64)]) -> i64 {
+    let &(idx, max_value) = buses.iter().max_by(|(_, x), (_, y)| x.cmp(y)).unwrap();
+    // the first departure at or after 0 where the bus leaves `idx` minutes later
+    let mut current_value = (-idx).rem_euclid(max_value);
+
+    loop {
+        trace!("Trying: {}", current_value);
+
+        if is_valid(current_value, buses) {
+            return current_value;
+        }
+
+        current_value += max_value;
+    }
+}
+
+/// Greatest common divisor of `a` and `b`.
+pub fn euclidean(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        return a;
+    }
+    euclidean(b, a % b)
+}
+
+/// Returns `(d, x, y)` such that `d = gcd(a, b) = a * x + b * y`.
+pub fn extended_euclidean(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (d, x0, y0) = extended_euclidean(b, a % b);
+    (d, y0, x0 - (a / b) * y0)
+}
+
+/// Returns `x` such that `a * x = 1 (mod modulo)`, if `a` and `modulo` are coprime.
+pub fn modular_inverse(a: i64, modulo: i64) -> Option<i64> {
+    let (d, x, _) = extended_euclidean(a, modulo);
+    if d != 1 {
+        return None;
+    }
+    Some(x)
+}
+
+/// Chinese remainder theorem: the smallest non-negative `t` such that `t + idx = 0 (mod bus)`
+/// for every `(idx, bus)`. The buses have to be pairwise coprime.
+pub fn solve_extended_euclidean(buses: &[(i64, i64)]) -> i64 {
+    let m: i64 = buses.iter().map(|&(_, b)| b).product();
+
+    let solution = buses
+        .iter()
+        .map(|&(idx, bus)| {
+            // t + idx = 0 mod bus => t = (bus - idx) mod bus
+            let a = -idx;
+            let b = bus;
+
+            let b_i = m / b;
+            let b_i_inverse = modular_inverse(b_i, b).expect("bus ids are not pairwise coprime");
+
+            a * b_i * b_i_inverse
+        })
+        .sum::<i64>();
+
+    solution.rem_euclid(m)
+}
+
+/// A departure time and a schedule of `size` positions (at least 1), some of them taken by
+/// buses with different prime ids.
+///
+/// There are only as many buses as the chinese remainder theorem can combine without
+/// overflowing.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let positions = size.max(1);
+
+    let mut primes: Vec<i64> = (7..1000)
+        .filter(|&n| (2..).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+    rng.shuffle(&mut primes);
+
+    // each bus adds a term of at most its position times the product of all ids
+    let limit = (1i64 << 62) / positions as i64;
+    let wanted = rng.range(1, 12).min(positions as u64) as usize;
+    let mut buses: Vec<i64> = Vec::new();
+    let mut product: i64 = 1;
+    for prime in primes {
+        if buses.len() == wanted {
+            break;
+        }
+        match product.checked_mul(prime) {
+            Some(p) if p.saturating_mul(buses.len() as i64 + 1) <= limit => {
+                product = p;
+                buses.push(prime);
+            }
+            _ => {}
+        }
+    }
+
+    let mut schedule = vec![String::from("x"); positions];
+    let taken = rng.distinct(0, positions as u64 - 1, buses.len());
+    for (position, bus) in taken.iter().zip(&buses) {
+        schedule[*position as usize] = bus.to_string();
+    }
+
+    format!("{}\n{}\n", rng.range(1000, 10_000_000), schedule.join(","))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (i64, Vec<(i64, i64)>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        read_input(input)
+    }
+
+    fn summary(&self, (time, buses): &Self::Input) -> Summary {
+        Summary::new()
+            .fact("earliest departure", time)
+            .fact("buses", buses.len())
+            .range("bus ids", buses.iter().map(|(_, bus)| bus))
+            .range("offsets", buses.iter().map(|(idx, _)| idx))
+    }
+
+    fn part1(&self, (time, buses): &Self::Input) -> Answer {
+        debug!("time: {}", time);
+        debug!("buses: {:?}", buses);
+
+        let (bus, earliest_time) = find_earliest_time(*time, buses);
+
+        Answer::from(bus * (earliest_time - time))
+    }
+
+    fn part2(&self, (_, buses): &Self::Input) -> Option<Answer> {
+        Some(Answer::from(solve_extended_euclidean(buses)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euclidean() {
+        assert_eq!(euclidean(12, 18), 6);
+        assert_eq!(euclidean(17, 5), 1);
+        assert_eq!(extended_euclidean(240, 46), (2, -9, 47));
+    }
+
+    #[test]
+    fn test_modular_inverse() {
+        assert_eq!(
+            modular_inverse(3, 7).map(|x| (3 * x).rem_euclid(7)),
+            Some(1)
+        );
+        assert_eq!(modular_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn test_find_time() {
+        assert_eq!(
+            find_time(&[(0, 7), (1, 13), (4, 59), (6, 31), (7, 19)]),
+            1068781
+        );
+        // departures at 0 count too, as for the chinese remainder theorem
+        assert_eq!(find_time(&[(0, 839)]), 0);
+        assert_eq!(find_time(&[(5, 3)]), 1);
+    }
+
+    #[test]
+    fn test_solve_extended_euclidean() {
+        assert_eq!(solve_extended_euclidean(&[(0, 17), (2, 13), (3, 19)]), 3417);
+        assert_eq!(
+            solve_extended_euclidean(&[(0, 7), (1, 13), (4, 59), (6, 31), (7, 19)]),
+            1068781
+        );
+    }
+}
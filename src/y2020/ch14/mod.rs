@@ -0,0 +1,231 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, parse_number};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mask(String),
+    Value(u64, u64),
+}
+
+fn extract_memory_location(line: &str, s: &str) -> Result<u64, Error> {
+    let slice = s
+        .strip_prefix("mem[")
+        .and_then(|s| s.strip_suffix("]"))
+        .ok_or_else(|| Error::parse(line, s, "expected a memory location like mem[8]"))?;
+    parse_number(line, slice)
+}
+
+impl Instruction {
+    fn from_line(line: &str) -> Result<Instruction, Error> {
+        let mut parts = line.split(" = ");
+        let target = parts.next().unwrap_or(line);
+        let value = parts
+            .next()
+            .ok_or_else(|| Error::parse(line, line, "expected an assignment"))?;
+
+        if target == "mask" {
+            if value.len() != 36 {
+                return Err(Error::parse(line, value, "expected a 36 bit mask"));
+            }
+            if let Some(idx) = value.find(|c| !matches!(c, '0' | '1' | 'X')) {
+                return Err(Error::parse(line, &value[idx..], "invalid mask bit"));
+            }
+            Ok(Instruction::Mask(String::from(value)))
+        } else if target.starts_with("mem") {
+            let memory_location = extract_memory_location(line, target)?;
+            let value = parse_number(line, value)?;
+            if value >= 1 << 36 {
+                return Err(Error::parse(line, line, "value does not fit in 36 bits"));
+            }
+
+            Ok(Instruction::Value(memory_location, value))
+        } else {
+            Err(Error::parse(line, target, "invalid instruction"))
+        }
+    }
+}
+
+fn to_binary_string(value: u64) -> String {
+    format!("{:0>36b}", value)
+}
+
+fn from_binary_string(value: &str) -> u64 {
+    u64::from_str_radix(value, 2)
+        .unwrap_or_else(|_| panic!("Could not convert binary string: {}", value))
+}
+
+fn apply_mask(mask: &str, value: u64) -> u64 {
+    let binary_string = to_binary_string(value);
+
+    let masked: String = binary_string
+        .chars()
+        .zip(mask.chars())
+        .map(
+            |(char, mask_char)| {
+                if mask_char != 'X' {
+                    mask_char
+                } else {
+                    char
+                }
+            },
+        )
+        .collect();
+
+    from_binary_string(masked.as_str())
+}
+
+fn read_input(input: &str) -> Result<Vec<Instruction>, Error> {
+    parse_lines(input, Instruction::from_line)
+}
+
+fn sum_memory(instructions: &[Instruction]) -> u64 {
+    let mut memory = HashMap::new();
+    let mut current_mask = "";
+
+    instructions.iter().for_each(|instr| match instr {
+        Instruction::Mask(mask) => {
+            current_mask = mask;
+        }
+        Instruction::Value(memory_location, value) => {
+            memory.insert(memory_location, apply_mask(current_mask, *value));
+        }
+    });
+
+    memory.values().sum()
+}
+
+fn apply_mask_floating(mask: &str, value: u64) -> String {
+    let binary_string = to_binary_string(value);
+
+    let masked_floating: String = binary_string
+        .chars()
+        .zip(mask.chars())
+        .map(|(char, mask_char)| {
+            if mask_char == '0' {
+                char
+            } else if mask_char == '1' {
+                '1'
+            } else {
+                'X'
+            }
+        })
+        .collect();
+
+    masked_floating
+}
+
+fn generate_locations(value: &str) -> Vec<String> {
+    let mut locations = vec![String::from("")];
+
+    value.chars().for_each(|c| {
+        if c != 'X' {
+            locations.iter_mut().for_each(|l| l.push(c));
+        } else {
+            let mut new_locations = Vec::new();
+
+            locations.iter_mut().for_each(|s| {
+                let mut new_location = String::from(s.as_str());
+                new_location.push('1');
+                new_locations.push(new_location);
+
+                s.push('0');
+            });
+
+            locations.append(&mut new_locations);
+        }
+    });
+
+    locations
+}
+
+fn sum_memory_floating(instructions: &[Instruction]) -> u64 {
+    let mut memory = HashMap::new();
+    let mut current_mask = "";
+
+    instructions.iter().for_each(|instr| match instr {
+        Instruction::Mask(mask) => {
+            current_mask = mask;
+        }
+        Instruction::Value(memory_location, value) => {
+            let floating_memory_location = apply_mask_floating(current_mask, *memory_location);
+
+            let memory_locations = generate_locations(&floating_memory_location);
+
+            memory_locations.iter().for_each(|l| {
+                memory.insert(String::from(l), *value);
+            });
+        }
+    });
+
+    memory.values().sum()
+}
+
+/// A program of `size` lines (at least 1): masks with up to 9 floating bits, each followed by
+/// a few writes.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut lines = 0;
+    while lines < size.max(1) {
+        let count = rng.range(0, 9) as usize;
+        let floating = rng.distinct(0, 35, count);
+        let mask: String = (0..36)
+            .map(|bit| {
+                if floating.contains(&bit) {
+                    'X'
+                } else if rng.chance(50) {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+        input.push_str(&format!("mask = {}\n", mask));
+        lines += 1;
+
+        for _ in 0..rng.range(1, 6) {
+            input.push_str(&format!(
+                "mem[{}] = {}\n",
+                rng.range(0, 65535),
+                rng.range(0, 999_999_999)
+            ));
+            lines += 1;
+        }
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        read_input(input)
+    }
+
+    fn summary(&self, instructions: &Vec<Instruction>) -> Summary {
+        let writes = || {
+            instructions.iter().filter_map(|i| match i {
+                Instruction::Value(location, value) => Some((*location, *value)),
+                Instruction::Mask(_) => None,
+            })
+        };
+        Summary::new()
+            .fact("masks", instructions.len() - writes().count())
+            .fact("writes", writes().count())
+            .range("addresses", writes().map(|(location, _)| location))
+            .range("values", writes().map(|(_, value)| value))
+    }
+
+    fn part1(&self, instructions: &Vec<Instruction>) -> Answer {
+        Answer::from(sum_memory(instructions))
+    }
+
+    fn part2(&self, instructions: &Vec<Instruction>) -> Option<Answer> {
+        Some(Answer::from(sum_memory_floating(instructions)))
+    }
+}
@@ -0,0 +1,274 @@
+use crate::answers::input_hash;
+use crate::check::Summary;
+use crate::checkpoint::{self, Checkpointer, Decoder, Encoder, Simulation, Snapshot};
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::progress::{self, Progress, Silent};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_number;
+use crate::warn;
+use std::collections::HashMap;
+
+/// The memory game after some numbers were spoken.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryGame {
+    starting_numbers: Vec<u64>,
+    // number of numbers spoken so far
+    turn: usize,
+    last_spoken_number: u64,
+    // number => last turn it was spoken on, except for the last number spoken
+    numbers_spoken: HashMap<u64, u64>,
+}
+
+impl MemoryGame {
+    pub fn new(numbers: &[u64]) -> MemoryGame {
+        let mut numbers_spoken: HashMap<u64, u64> = numbers
+            .iter()
+            .enumerate()
+            .map(|(idx, n)| (*n, idx as u64))
+            .collect();
+        let last_spoken_number = numbers[numbers.len() - 1];
+        numbers_spoken.remove(&last_spoken_number);
+
+        MemoryGame {
+            starting_numbers: numbers.to_vec(),
+            turn: numbers.len(),
+            last_spoken_number,
+            numbers_spoken,
+        }
+    }
+
+    pub fn last_spoken_number(&self) -> u64 {
+        self.last_spoken_number
+    }
+
+    /// Keeps speaking until `last_iteration` numbers were spoken.
+    pub fn play_until(&mut self, last_iteration: usize) {
+        self.play_until_reporting(last_iteration, &mut Silent);
+    }
+
+    /// Like [`MemoryGame::play_until`], reporting the number of turns played to `progress`.
+    pub fn play_until_reporting(&mut self, last_iteration: usize, progress: &mut dyn Progress) {
+        let stride = progress::stride(last_iteration);
+        while self.turn < last_iteration {
+            let next = last_iteration.min(self.turn + stride);
+            self.play_turns(next);
+            progress.update(self.turn, Some(last_iteration));
+        }
+        progress.finish();
+    }
+
+    fn play_turns(&mut self, last_iteration: usize) {
+        for iteration in self.turn..last_iteration {
+            let previous_iteration = iteration as u64 - 1;
+            let previous_number_spoken = self.last_spoken_number;
+
+            self.last_spoken_number = match self.numbers_spoken.get(&previous_number_spoken) {
+                Some(spoken) => previous_iteration - spoken,
+                None => 0,
+            };
+
+            self.numbers_spoken
+                .insert(previous_number_spoken, previous_iteration);
+        }
+        self.turn = self.turn.max(last_iteration);
+    }
+}
+
+impl Snapshot for MemoryGame {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.values(self.starting_numbers.iter().copied());
+        encoder.u64(self.turn as u64);
+        encoder.u64(self.last_spoken_number);
+        encoder.u64(self.numbers_spoken.len() as u64);
+        for (&n, &turn) in &self.numbers_spoken {
+            encoder.u64(n);
+            encoder.u64(turn);
+        }
+    }
+
+    fn decode(decoder: &mut Decoder) -> Option<MemoryGame> {
+        let starting_numbers = decoder.values()?;
+        let turn = decoder.u64()? as usize;
+        let last_spoken_number = decoder.u64()?;
+        let entries = decoder.u64()?;
+        let numbers_spoken = (0..entries)
+            .map(|_| Some((decoder.u64()?, decoder.u64()?)))
+            .collect::<Option<_>>()?;
+        if starting_numbers.is_empty() {
+            return None;
+        }
+
+        Some(MemoryGame {
+            starting_numbers,
+            turn,
+            last_spoken_number,
+            numbers_spoken,
+        })
+    }
+}
+
+impl Simulation for MemoryGame {
+    fn position(&self) -> usize {
+        self.turn
+    }
+
+    fn advance_to(&mut self, position: usize) {
+        self.play_until(position);
+    }
+
+    fn continues(&self, start: &MemoryGame) -> bool {
+        self.starting_numbers == start.starting_numbers
+    }
+}
+
+/// The number spoken on turn `last_iteration` if it is one of the starting `numbers`.
+///
+/// Turns are counted from 1: panics if `last_iteration` is 0, nothing is spoken before.
+fn starting_number(numbers: &[u64], last_iteration: usize) -> Option<u64> {
+    assert!(last_iteration > 0, "turns are counted from 1");
+    numbers.get(last_iteration - 1).copied()
+}
+
+/// The `last_iteration`th number spoken in the game starting with `numbers`.
+pub fn find_number(numbers: &[u64], last_iteration: usize, progress: &mut dyn Progress) -> u64 {
+    if let Some(number) = starting_number(numbers, last_iteration) {
+        return number;
+    }
+    let mut game = MemoryGame::new(numbers);
+    game.play_until_reporting(last_iteration, progress);
+    game.last_spoken_number()
+}
+
+/// Like [`find_number`], saving the game with `checkpointer` and resuming from its last save.
+pub fn find_number_resumable(
+    numbers: &[u64],
+    last_iteration: usize,
+    checkpointer: &Checkpointer,
+    progress: &mut dyn Progress,
+) -> Result<u64, Error> {
+    if let Some(number) = starting_number(numbers, last_iteration) {
+        return Ok(number);
+    }
+    let key = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let name = format!("ch15-{:016x}", input_hash(&key));
+
+    let game = checkpointer.run(&name, MemoryGame::new(numbers), last_iteration, progress)?;
+    Ok(game.last_spoken_number())
+}
+
+/// `size` different starting numbers (at least 1).
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let numbers = rng.distinct(0, (count as u64 * 3).max(20), count);
+
+    let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    numbers.join(",") + "\n"
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, Error> {
+        let line = input.trim();
+        if line.is_empty() {
+            return Err(Error::end_of_input(input, "missing starting numbers"));
+        }
+        // blank lines before the numbers still count when reporting errors
+        let skipped_lines = input[..input.len() - input.trim_start().len()]
+            .matches('\n')
+            .count();
+
+        line.split(',')
+            .map(|n| parse_number(line, n))
+            .collect::<Result<_, Error>>()
+            .map_err(|e| e.offset_lines(skipped_lines))
+    }
+
+    fn summary(&self, numbers: &Vec<u64>) -> Summary {
+        Summary::new()
+            .fact("starting numbers", numbers.len())
+            .range("values", numbers.iter())
+    }
+
+    fn part1(&self, numbers: &Vec<u64>) -> Answer {
+        Answer::from(find_number(numbers, 2020, &mut Silent))
+    }
+
+    fn part2(&self, numbers: &Vec<u64>) -> Option<Answer> {
+        let last_iteration = 30000000;
+        let mut progress = progress::reporter("day 15");
+
+        if let Some(checkpointer) = checkpoint::configured() {
+            match find_number_resumable(numbers, last_iteration, &checkpointer, progress.as_mut()) {
+                Ok(n) => return Some(Answer::from(n)),
+                Err(e) => warn!("{}, playing without checkpoints", e),
+            }
+        }
+
+        let n = find_number(numbers, last_iteration, progress.as_mut());
+        Some(Answer::from(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_number_works() {
+        let last_iteration = 2020;
+        assert_eq!(find_number(&[0, 3, 6], last_iteration, &mut Silent), 436);
+        assert_eq!(find_number(&[1, 3, 2], last_iteration, &mut Silent), 1);
+        assert_eq!(find_number(&[2, 1, 3], last_iteration, &mut Silent), 10);
+        assert_eq!(find_number(&[1, 2, 3], last_iteration, &mut Silent), 27);
+        assert_eq!(find_number(&[2, 3, 1], last_iteration, &mut Silent), 78);
+        assert_eq!(find_number(&[3, 2, 1], last_iteration, &mut Silent), 438);
+        assert_eq!(find_number(&[3, 1, 2], last_iteration, &mut Silent), 1836);
+    }
+
+    #[test]
+    fn test_find_number_short_games() {
+        // 0, 3, 6, 0, 3, 3, 1, 0, 4, 0
+        let mut updates = Vec::new();
+        let mut progress = progress::Callback(|done, _| updates.push(done));
+        assert_eq!(find_number(&[0, 3, 6], 4, &mut progress), 0);
+        assert_eq!(find_number(&[0, 3, 6], 9, &mut progress), 4);
+        assert_eq!(find_number(&[0, 3, 6], 3, &mut progress), 6);
+        assert_eq!(find_number(&[0, 3, 6], 2, &mut progress), 3);
+        assert_eq!(find_number(&[0, 3, 6], 1, &mut progress), 0);
+        assert_eq!(updates, vec![4, 4, 5, 6, 7, 8, 9]);
+
+        // there is no turn 0
+        assert!(std::panic::catch_unwind(|| find_number(&[0, 3, 6], 0, &mut Silent)).is_err());
+    }
+
+    #[test]
+    fn test_memory_game_snapshot() {
+        let mut game = MemoryGame::new(&[0, 3, 6]);
+        game.play_until(10);
+
+        let checkpointer = Checkpointer::new(
+            std::env::temp_dir().join(format!("code-advent-2020-ch15-{}", std::process::id())),
+            100,
+        );
+        checkpointer.save("game", &game).unwrap();
+
+        let mut resumed: MemoryGame = checkpointer.load("game").unwrap().unwrap();
+        assert_eq!(resumed, game);
+        resumed.play_until(2020);
+        assert_eq!(resumed.last_spoken_number(), 436);
+        assert_eq!(
+            find_number_resumable(&[0, 3, 6], 2020, &checkpointer, &mut Silent),
+            Ok(436)
+        );
+
+        std::fs::remove_dir_all(checkpointer.directory()).unwrap();
+    }
+}
@@ -0,0 +1,332 @@
+use crate::check::Summary;
+use crate::debug;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::parse::{self, Cursor};
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, read_numbered_blocks};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug)]
+pub struct Rule {
+    field: String,
+    ranges: Vec<(u64, u64)>,
+}
+
+fn parse_range(cursor: &mut Cursor) -> Result<(u64, u64), Error> {
+    let start = cursor.integer()?;
+    cursor.tag("-")?;
+    Ok((start, cursor.integer()?))
+}
+
+impl Rule {
+    fn from_line(line: &str) -> Result<Rule, Error> {
+        parse::line(line, |cursor| {
+            let (field, ranges) =
+                cursor.field(": ", |cursor| cursor.separated(" or ", parse_range))?;
+            Ok(Rule {
+                field: String::from(field),
+                ranges,
+            })
+        })
+    }
+
+    fn contain(&self, value: &u64) -> bool {
+        self.ranges.iter().any(|(l, r)| l <= value && value <= r)
+    }
+}
+
+#[derive(Debug)]
+pub struct Ticket {
+    values: Vec<u64>,
+}
+
+impl Ticket {
+    fn from_line(line: &str) -> Result<Ticket, Error> {
+        let values = parse::line(line, |cursor| cursor.separated(",", |c| c.integer()))?;
+        Ok(Ticket { values })
+    }
+}
+
+type Notes = (Vec<Rule>, Ticket, Vec<Ticket>);
+
+/// Parses a block of tickets that starts with the given header line.
+fn read_tickets(
+    input: &str,
+    block: Option<&(usize, String)>,
+    header: &str,
+    rules: usize,
+) -> Result<Vec<Ticket>, Error> {
+    let (first_line, block) =
+        block.ok_or_else(|| Error::end_of_input(input, format!("missing '{}'", header)))?;
+
+    let mut lines = block.lines();
+    let header_line = lines.next().unwrap_or_default();
+    if header_line != header {
+        return Err(
+            Error::parse(header_line, header_line, format!("expected '{}'", header))
+                .offset_lines(*first_line),
+        );
+    }
+
+    parse_lines(&lines.collect::<Vec<_>>().join("\n"), |line| {
+        let ticket = Ticket::from_line(line)?;
+        if ticket.values.len() != rules {
+            return Err(Error::parse(
+                line,
+                line,
+                format!("expected {} values", rules),
+            ));
+        }
+        Ok(ticket)
+    })
+    .map_err(|e| e.offset_lines(first_line + 1))
+}
+
+fn read_input(input: &str) -> Result<Notes, Error> {
+    let blocks = read_numbered_blocks(input);
+    let mut it = blocks.iter();
+
+    let (first_line, rules) = it
+        .next()
+        .ok_or_else(|| Error::end_of_input(input, "missing rules"))?;
+    let rules: Vec<Rule> =
+        parse_lines(rules, Rule::from_line).map_err(|e| e.offset_lines(*first_line))?;
+
+    let ticket = read_tickets(input, it.next(), "your ticket:", rules.len())?
+        .pop()
+        .ok_or_else(|| Error::end_of_input(input, "missing your ticket"))?;
+
+    let nearby_tickets = read_tickets(input, it.next(), "nearby tickets:", rules.len())?;
+
+    Ok((rules, ticket, nearby_tickets))
+}
+
+fn is_valid(value: u64, rules: &[Rule]) -> bool {
+    rules.iter().any(|r| r.contain(&value))
+}
+
+fn ticket_scanning_error_rate(rules: &[Rule], nearby_tickets: &[Ticket]) -> u64 {
+    nearby_tickets
+        .iter()
+        .map(|t| -> u64 {
+            t.values
+                .iter()
+                .map(|v| if is_valid(*v, rules) { 0 } else { *v })
+                .sum()
+        })
+        .sum()
+}
+
+fn find_field_order<'a>(tickets: &Vec<&Ticket>, rules: &'a Vec<Rule>) -> HashMap<&'a str, usize> {
+    /*
+    first   ->          row
+    second  ->  class + row
+    third   ->  class + row + seat
+
+    row     -> 1 + 2 + 3
+    class   ->     2 + 3
+    seat    ->         3
+     */
+    let mut field_columns: HashMap<&str, HashSet<usize>> = HashMap::new();
+
+    let mut fields_in_degree = HashMap::new();
+    let column_count = rules.len();
+
+    for rule in rules {
+        let mut possible_columns = HashSet::new();
+
+        for column in 0..column_count {
+            if tickets.iter().all(|t| rule.contain(&t.values[column])) {
+                possible_columns.insert(column);
+                fields_in_degree
+                    .entry(&rule.field)
+                    .and_modify(|d| *d += 1)
+                    .or_insert(1);
+            }
+        }
+
+        field_columns.insert(&*rule.field, possible_columns);
+    }
+
+    let mut result = HashMap::new();
+
+    let mut queue = VecDeque::new();
+    fields_in_degree.iter().for_each(|(a, b)| {
+        if *b == 1 {
+            queue.push_back(a.as_str());
+        }
+    });
+
+    while let Some(current_field) = queue.pop_front() {
+        let map_entry = field_columns.remove(current_field).unwrap();
+        let column = *map_entry.iter().next().unwrap();
+        result.insert(current_field, column);
+
+        for (field, columns) in &mut field_columns {
+            columns.remove(&column);
+
+            if columns.len() == 1 {
+                queue.push_back(*field);
+            }
+        }
+    }
+
+    result
+}
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// Notes on 20 fields with `size` nearby tickets, about one in five of them invalid.
+///
+/// Fields are ranked: the values of the column of rank `r` are drawn from the band of rank `r`,
+/// and the rule of rank `r` accepts the bands of rank `r` and above, so the fields can be
+/// assigned from the highest rank down.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    const BAND: u64 = 40;
+    let fields = FIELDS.len();
+    let start = |rank: usize| 30 + BAND * rank as u64;
+    // the last value of each band is only valid for the lower ranks
+    let end = |rank: usize| start(rank) + BAND - 1;
+    let top = end(fields - 1) + BAND;
+
+    let mut field_ranks: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut field_ranks);
+    let mut column_ranks: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut column_ranks);
+
+    let mut input = String::new();
+    for (field, &rank) in FIELDS.iter().zip(&field_ranks) {
+        input.push_str(&format!(
+            "{}: {}-{} or {}-{}\n",
+            field,
+            start(rank),
+            end(rank) - 1,
+            end(rank) + 1,
+            top
+        ));
+    }
+
+    let ticket = |rng: &mut Rng, valid: bool| {
+        let mut values: Vec<u64> = column_ranks
+            .iter()
+            .map(|&rank| rng.range(start(rank), end(rank) - 1))
+            .collect();
+        if !valid {
+            let column = rng.index(values.len());
+            values[column] = if rng.chance(50) {
+                rng.range(0, start(0) - 1)
+            } else {
+                rng.range(top + 1, 999)
+            };
+        }
+        let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        values.join(",") + "\n"
+    };
+
+    input.push_str("\nyour ticket:\n");
+    input.push_str(&ticket(rng, true));
+    input.push_str("\nnearby tickets:\n");
+    for _ in 0..size {
+        let valid = rng.chance(80);
+        input.push_str(&ticket(rng, valid));
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Notes, Error> {
+        read_input(input)
+    }
+
+    fn summary(&self, (rules, ticket, nearby_tickets): &Notes) -> Summary {
+        let values = nearby_tickets.iter().flat_map(|t| t.values.iter());
+        Summary::new()
+            .fact("fields", rules.len())
+            .range("ranges per field", rules.iter().map(|r| r.ranges.len()))
+            .fact("values on your ticket", ticket.values.len())
+            .fact("nearby tickets", nearby_tickets.len())
+            .range("nearby values", values)
+    }
+
+    fn part1(&self, (rules, _, nearby_tickets): &Notes) -> Answer {
+        Answer::from(ticket_scanning_error_rate(rules, nearby_tickets))
+    }
+
+    fn part2(&self, (rules, ticket, nearby_tickets): &Notes) -> Option<Answer> {
+        let valid_nearby_tickets: Vec<&Ticket> = nearby_tickets
+            .iter()
+            .filter(|t| t.values.iter().all(|v| is_valid(*v, rules)))
+            .collect();
+
+        let field_columns = find_field_order(&valid_nearby_tickets, rules);
+
+        for entry in &field_columns {
+            debug!("{:?}", entry);
+        }
+
+        let result: u64 = field_columns
+            .iter()
+            .filter(|(f, _)| f.starts_with("departure"))
+            .map(|(_, c)| ticket.values[*c])
+            .product();
+
+        Some(Answer::from(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+
+    #[test]
+    fn test_read_input_line_endings() {
+        for input in [EXAMPLE.to_string(), EXAMPLE.replace('\n', "\r\n")].iter() {
+            let (rules, ticket, nearby_tickets) = read_input(input).unwrap();
+
+            assert_eq!(rules.len(), 3);
+            assert_eq!(ticket.values, vec![7, 1, 14]);
+            assert_eq!(nearby_tickets.len(), 4);
+            assert_eq!(ticket_scanning_error_rate(&rules, &nearby_tickets), 71);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::automaton::{Automaton, Lattice, Rule};
+use crate::check::Summary;
+use crate::error::Error;
+use crate::export::{self, Color};
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use crate::{debug, trace};
+use std::collections::HashSet;
+use std::fmt;
+
+const CYCLES: usize = 6;
+
+#[derive(Clone)]
+pub enum Tile {
+    Inactive,
+    Active,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Inactive => {
+                write!(f, ".")
+            }
+            Tile::Active => {
+                write!(f, "#")
+            }
+        }
+    }
+}
+
+type Slice = Grid<Tile>;
+
+fn parse_slice(input: &str) -> Result<Slice, Error> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(Tile::Active),
+        '.' => Some(Tile::Inactive),
+        _ => None,
+    })
+}
+
+/// Active cubes of the initial slice, at `z = 0` (and `w = 0`).
+fn active_cubes<const N: usize>(slice: &Slice) -> Vec<[i32; N]> {
+    slice
+        .iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Active))
+        .map(|((x, y), _)| {
+            let mut cube = [0; N];
+            cube[0] = x as i32;
+            cube[1] = y as i32;
+            cube
+        })
+        .collect()
+}
+
+/// The z levels spanned by the active cubes, within their bounding box.
+fn render_levels(cubes: &[[i32; 3]]) -> String {
+    let bounds = |axis: usize| {
+        let values = cubes.iter().map(|c| c[axis]);
+        (values.clone().min().unwrap_or(0), values.max().unwrap_or(0))
+    };
+    let ((min_x, max_x), (min_y, max_y), (min_z, max_z)) = (bounds(0), bounds(1), bounds(2));
+
+    let mut s = String::new();
+    for z in min_z..=max_z {
+        let level = Grid::from_fn(
+            (max_x - min_x + 1) as usize,
+            (max_y - min_y + 1) as usize,
+            |x, y| {
+                let cube = [x as i32 + min_x, y as i32 + min_y, z];
+                if cubes.contains(&cube) {
+                    Tile::Active
+                } else {
+                    Tile::Inactive
+                }
+            },
+        );
+        s.push_str(&format!("z={}\n{}", z, level));
+    }
+    s
+}
+
+/// An initial slice of `size` by `size` cubes (at least 1), about a third of them active.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let slice = Grid::from_fn(size.max(1), size.max(1), |_, _| rng.chance(33));
+    slice.render(|&active| if active { '#' } else { '.' })
+}
+
+/// The cubes before the first cycle and after each cycle, in 3 dimensions for part 1 and 4 for
+/// part 2. A frame shows all the slices side by side, those with the same `w` on a row.
+pub(crate) fn frames(input: &str, part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    let slice = parse_slice(input)?;
+    Ok(match part {
+        1 => render_cycles::<3>(&slice),
+        _ => render_cycles::<4>(&slice),
+    })
+}
+
+fn render_cycles<const N: usize>(slice: &Slice) -> Vec<Grid<Color>> {
+    let mut grid = Automaton::sparse(Lattice::<N>, Rule::life(), active_cubes(slice));
+    let mut cycles = vec![grid.live_cells()];
+    grid.run(CYCLES, |grid| cycles.push(grid.live_cells()));
+
+    // all the frames have the bounds of the largest cycle
+    let mut min = [0; N];
+    let mut max = [0; N];
+    for axis in 0..N {
+        let values = cycles.iter().flatten().map(|cube| cube[axis]);
+        min[axis] = values.clone().min().unwrap_or(0);
+        max[axis] = values.max().unwrap_or(0);
+    }
+    let (width, height) = (
+        (max[0] - min[0] + 1) as usize,
+        (max[1] - min[1] + 1) as usize,
+    );
+    let ws = match N {
+        3 => 0..=0,
+        _ => min[3]..=max[3],
+    };
+    let inactive = Color(20, 20, 40);
+
+    cycles
+        .iter()
+        .map(|cubes| {
+            let active: HashSet<&[i32; N]> = cubes.iter().collect();
+            let slices: Vec<Grid<Color>> = ws
+                .clone()
+                .flat_map(|w| (min[2]..=max[2]).map(move |z| [z, w]))
+                .map(|higher| {
+                    Grid::from_fn(width, height, |x, y| {
+                        let mut cube = [0; N];
+                        cube[0] = x as i32 + min[0];
+                        cube[1] = y as i32 + min[1];
+                        cube[2..].copy_from_slice(&higher[..N - 2]);
+                        if active.contains(&cube) {
+                            Color(80, 200, 255)
+                        } else {
+                            inactive
+                        }
+                    })
+                })
+                .collect();
+            export::tile(&slices, (max[2] - min[2] + 1) as usize, inactive)
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Slice;
+
+    fn parse(&self, input: &str) -> Result<Slice, Error> {
+        parse_slice(input)
+    }
+
+    fn summary(&self, initial_grid: &Slice) -> Summary {
+        Summary::new()
+            .fact("rows", initial_grid.height())
+            .fact("columns", initial_grid.width())
+            .fact(
+                "active cubes",
+                initial_grid.count(|tile| matches!(tile, Tile::Active)),
+            )
+    }
+
+    fn part1(&self, initial_grid: &Slice) -> Answer {
+        let mut grid = Automaton::sparse(Lattice::<3>, Rule::life(), active_cubes(initial_grid));
+
+        trace!("After 0 cycles:\n{}", render_levels(&grid.live_cells()));
+        grid.run(CYCLES, |grid| {
+            trace!(
+                "After {} cycles:\n{}",
+                grid.generation(),
+                render_levels(&grid.live_cells())
+            );
+            debug!("Active cubes: {}", grid.count_alive());
+        });
+
+        Answer::from(grid.count_alive())
+    }
+
+    fn part2(&self, initial_grid: &Slice) -> Option<Answer> {
+        let mut grid = Automaton::sparse(Lattice::<4>, Rule::life(), active_cubes(initial_grid));
+
+        grid.run(CYCLES, |_| {});
+
+        Some(Answer::from(grid.count_alive()))
+    }
+}
@@ -0,0 +1,467 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, parse_number};
+use std::collections::VecDeque;
+
+fn count_starting_parenthesis(s: &str) -> usize {
+    let mut cnt = 0;
+
+    for c in s.chars() {
+        if c == '(' {
+            cnt += 1
+        } else {
+            return cnt;
+        }
+    }
+
+    cnt
+}
+
+fn strip_starting_chars(s: &str, c: usize) -> &str {
+    &s[c..]
+}
+
+fn strip_ending_chars(s: &str, c: usize) -> &str {
+    &s[..s.len() - c]
+}
+
+fn count_ending_paranthesis(s: &str) -> usize {
+    let mut cnt = 0;
+
+    for c in s.chars().rev() {
+        if c == ')' {
+            cnt += 1
+        } else {
+            return cnt;
+        }
+    }
+
+    cnt
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operation {
+    Addition,
+    Multiplication,
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Number(i64),
+    Expression(Expression),
+}
+
+impl Operand {
+    fn reduce(&mut self) -> i64 {
+        match self {
+            Operand::Number(n) => *n,
+            Operand::Expression(e) => e.reduce(),
+        }
+    }
+
+    fn reduce_with_different_precedence(&mut self) -> i64 {
+        match self {
+            Operand::Number(n) => *n,
+            Operand::Expression(e) => e.reduce_with_different_precedence(),
+        }
+    }
+}
+
+/// An arithmetic expression made of numbers, `+`, `*` and parentheses.
+#[derive(Debug, Clone)]
+pub struct Expression {
+    operands: VecDeque<Operand>,
+    operations: VecDeque<Operation>,
+}
+
+impl Expression {
+    fn new() -> Expression {
+        Expression {
+            operands: VecDeque::new(),
+            operations: VecDeque::new(),
+        }
+    }
+
+    fn add_operand(&mut self, expr: &str, token: &str, op: Operand) -> Result<(), Error> {
+        if self.operands.len() != self.operations.len() {
+            return Err(Error::parse(expr, token, "expected an operator"));
+        }
+        self.operands.push_back(op);
+        Ok(())
+    }
+
+    fn add_operation(&mut self, expr: &str, token: &str, op: Operation) -> Result<(), Error> {
+        if self.operands.len() != self.operations.len() + 1 {
+            return Err(Error::parse(expr, token, "expected an operand"));
+        }
+        self.operations.push_back(op);
+        Ok(())
+    }
+
+    fn is_complete(&self) -> bool {
+        self.operands.len() == self.operations.len() + 1
+    }
+
+    /// Parses an expression whose tokens are separated by spaces, like `2 * (3 + 4)`.
+    pub fn parse(expr: &str) -> Result<Expression, Error> {
+        let mut stack = VecDeque::new();
+        stack.push_back(Expression::new());
+
+        for t in expr.split(" ") {
+            let levels_to_open = count_starting_parenthesis(t);
+            let rest = strip_starting_chars(t, levels_to_open);
+            let levels_to_close = count_ending_paranthesis(rest);
+            let token = strip_ending_chars(rest, levels_to_close);
+
+            for _ in 0..levels_to_open {
+                stack.push_back(Expression::new());
+            }
+
+            let current = stack.back_mut().unwrap();
+            match token {
+                "+" if t == token => current.add_operation(expr, t, Operation::Addition)?,
+                "*" if t == token => current.add_operation(expr, t, Operation::Multiplication)?,
+                x => current.add_operand(expr, x, Operand::Number(parse_number(expr, x)?))?,
+            }
+
+            for _ in 0..levels_to_close {
+                if stack.len() == 1 {
+                    return Err(Error::parse(expr, t, "unbalanced ')'"));
+                }
+                let last = stack.pop_back().unwrap();
+                if !last.is_complete() {
+                    return Err(Error::parse(expr, t, "expected an operand"));
+                }
+                stack
+                    .back_mut()
+                    .unwrap()
+                    .add_operand(expr, t, Operand::Expression(last))?;
+            }
+        }
+
+        if stack.len() > 1 {
+            return Err(Error::parse(expr, expr, "unbalanced '('"));
+        }
+        let expression = stack.pop_front().unwrap();
+        if !expression.is_complete() {
+            return Err(Error::parse(expr, expr, "incomplete expression"));
+        }
+
+        Ok(expression)
+    }
+
+    /// Evaluates from left to right, `+` and `*` having the same precedence.
+    pub fn evaluate(&self) -> i64 {
+        self.clone().reduce()
+    }
+
+    /// Evaluates with `+` taking precedence over `*`.
+    pub fn evaluate_with_different_precedence(&self) -> i64 {
+        self.clone().reduce_with_different_precedence()
+    }
+
+    // the reductions consume the operands and operations of the expression
+    fn reduce_one(&mut self) -> i64 {
+        let a = self.operands.pop_front().unwrap().reduce();
+        let b = self.operands.pop_front().unwrap().reduce();
+
+        match &self.operations.pop_front().unwrap() {
+            Operation::Addition => a + b,
+            Operation::Multiplication => a * b,
+        }
+    }
+
+    fn reduce(&mut self) -> i64 {
+        if self.operands.len() == 1 {
+            return self.operands.pop_front().unwrap().reduce();
+        }
+        while self.operands.len() > 2 {
+            let c = self.reduce_one();
+            self.operands.push_front(Operand::Number(c));
+        }
+        self.reduce_one()
+    }
+
+    fn reduce_one_with_different_precedence(&mut self) -> i64 {
+        let a = self
+            .operands
+            .pop_front()
+            .unwrap()
+            .reduce_with_different_precedence();
+        let b = self
+            .operands
+            .pop_front()
+            .unwrap()
+            .reduce_with_different_precedence();
+
+        match &self.operations.pop_front().unwrap() {
+            Operation::Addition => a + b,
+            Operation::Multiplication => a * b,
+        }
+    }
+
+    fn reduce_with_different_precedence(&mut self) -> i64 {
+        if self.operands.len() == 1 {
+            return self
+                .operands
+                .pop_front()
+                .unwrap()
+                .reduce_with_different_precedence();
+        }
+        while self.operands.len() > 2 {
+            if self.operations.len() > 1
+                && self.operations[0] != Operation::Addition
+                && self.operations[1] == Operation::Addition
+            {
+                let first_operand = self.operands.pop_front().unwrap();
+                let first_operation = self.operations.pop_front().unwrap();
+
+                let c = self.reduce_one_with_different_precedence();
+                self.operands.push_front(Operand::Number(c));
+
+                self.operands.push_front(first_operand);
+                self.operations.push_front(first_operation);
+            } else {
+                let c = self.reduce_one_with_different_precedence();
+                self.operands.push_front(Operand::Number(c));
+            }
+        }
+        self.reduce_one_with_different_precedence()
+    }
+}
+
+fn read_expressions(input: &str) -> Result<Vec<Expression>, Error> {
+    parse_lines(input, Expression::parse)
+}
+
+/// A random expression nested `depth` levels deep, along with a bound on its value whatever
+/// the precedence of the operators.
+fn generate_expression(rng: &mut Rng, depth: usize) -> (String, u64) {
+    let operands = rng.range(2, if depth == 0 { 6 } else { 4 });
+
+    let mut expression = String::new();
+    // combining values below `a` and `b` by addition or multiplication gives one below `a * b`
+    let mut bound: u64 = 1;
+    for idx in 0..operands {
+        if idx > 0 {
+            expression.push_str(if rng.chance(50) { " + " } else { " * " });
+        }
+        let (operand, operand_bound) = if depth < 3 && rng.chance(25) {
+            let (inner, inner_bound) = generate_expression(rng, depth + 1);
+            (format!("({})", inner), inner_bound)
+        } else {
+            let digit = rng.range(1, 9);
+            (digit.to_string(), digit + 1)
+        };
+        expression.push_str(&operand);
+        bound = bound.saturating_mul(operand_bound);
+    }
+
+    (expression, bound)
+}
+
+/// `size` expressions of digits, additions, multiplications and parentheses, each of them
+/// below 10^12.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let expression = loop {
+            match generate_expression(rng, 0) {
+                (expression, bound) if bound <= 1_000_000_000_000 => break expression,
+                _ => continue,
+            }
+        };
+        input.push_str(&expression);
+        input.push('\n');
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Expression>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Expression>, Error> {
+        read_expressions(input)
+    }
+
+    fn summary(&self, expressions: &Vec<Expression>) -> Summary {
+        Summary::new().fact("expressions", expressions.len())
+    }
+
+    fn part1(&self, expressions: &Vec<Expression>) -> Answer {
+        let result: i64 = expressions.iter().map(|e| e.evaluate()).sum();
+
+        Answer::from(result)
+    }
+
+    fn part2(&self, expressions: &Vec<Expression>) -> Option<Answer> {
+        let result: i64 = expressions
+            .iter()
+            .map(|e| e.evaluate_with_different_precedence())
+            .sum();
+
+        Some(Answer::from(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_starting_parenthesis_works() {
+        assert_eq!(count_starting_parenthesis(""), 0);
+        assert_eq!(count_starting_parenthesis("()("), 1);
+        assert_eq!(count_starting_parenthesis("a("), 0);
+        assert_eq!(count_starting_parenthesis("(("), 2);
+        assert_eq!(count_starting_parenthesis("(((a"), 3);
+    }
+
+    #[test]
+    fn strip_starting_chars_works() {
+        assert_eq!(strip_starting_chars("", 0), "");
+        assert_eq!(strip_starting_chars("()(", 1), ")(");
+        assert_eq!(strip_starting_chars("a(", 0), "a(");
+        assert_eq!(strip_starting_chars("((", 2), "");
+        assert_eq!(strip_starting_chars("(((a", 3), "a");
+    }
+
+    #[test]
+    fn count_ending_paranthesis_works() {
+        assert_eq!(count_ending_paranthesis(""), 0);
+        assert_eq!(count_ending_paranthesis("("), 0);
+        assert_eq!(count_ending_paranthesis("()"), 1);
+        assert_eq!(count_ending_paranthesis(")"), 1);
+        assert_eq!(count_ending_paranthesis("))"), 2);
+        assert_eq!(count_ending_paranthesis("a)))"), 3);
+    }
+
+    #[test]
+    fn strip_ending_chars_works() {
+        assert_eq!(strip_ending_chars("", 0), "");
+        assert_eq!(strip_ending_chars("(", 0), "(");
+        assert_eq!(strip_ending_chars("()", 1), "(");
+        assert_eq!(strip_ending_chars(")", 1), "");
+        assert_eq!(strip_ending_chars("))", 2), "");
+        assert_eq!(strip_ending_chars("a)))", 3), "a");
+    }
+
+    #[test]
+    fn evaluate_expression_no_paranthesis_works() {
+        assert_eq!(
+            Expression::parse("1 + 2 * 3 + 4 * 5 + 6")
+                .unwrap()
+                .evaluate(),
+            71
+        );
+    }
+
+    #[test]
+    fn evaluate_expression_with_parenthesis_depth_one_works() {
+        assert_eq!(Expression::parse("2 * 3 + (4 * 5)").unwrap().evaluate(), 26);
+        assert_eq!(
+            Expression::parse("5 + (8 * 3 + 9 + 3 * 4 * 3)")
+                .unwrap()
+                .evaluate(),
+            437
+        );
+    }
+
+    #[test]
+    fn evaluate_expression_with_parenthesis_depth_three_works() {
+        assert_eq!(
+            Expression::parse("1 + (2 * 3) + (4 * (5 + 6))")
+                .unwrap()
+                .evaluate(),
+            51
+        );
+        assert_eq!(
+            Expression::parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")
+                .unwrap()
+                .evaluate(),
+            12240
+        );
+        assert_eq!(
+            Expression::parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")
+                .unwrap()
+                .evaluate(),
+            13632
+        );
+    }
+
+    #[test]
+    fn evaluate_with_different_precedence_expression_no_paranthesis_works() {
+        assert_eq!(
+            Expression::parse("1 + 2 * 3 + 4 * 5 + 6")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            231
+        );
+    }
+
+    #[test]
+    fn evaluate_with_different_precedence_expression_with_parenthesis_depth_one_works() {
+        assert_eq!(
+            Expression::parse("2 * 3 + (4 * 5)")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            46
+        );
+        assert_eq!(
+            Expression::parse("5 + (8 * 3 + 9 + 3 * 4 * 3)")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            1445
+        );
+    }
+
+    #[test]
+    fn evaluate_with_different_precedence_expression_with_parenthesis_depth_three_works() {
+        assert_eq!(
+            Expression::parse("1 + (2 * 3) + (4 * (5 + 6))")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            51
+        );
+        assert_eq!(
+            Expression::parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            669060
+        );
+        assert_eq!(
+            Expression::parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            23340
+        );
+    }
+
+    #[test]
+    fn evaluate_single_operand_works() {
+        assert_eq!(Expression::parse("7").unwrap().evaluate(), 7);
+        assert_eq!(
+            Expression::parse("((7)) * 2")
+                .unwrap()
+                .evaluate_with_different_precedence(),
+            14
+        );
+    }
+
+    #[test]
+    fn parse_expression_invalid() {
+        assert_eq!(
+            Expression::parse("1 + x").unwrap_err(),
+            Error::parse("1 + x", "x", "invalid number")
+        );
+        assert!(Expression::parse("1 + (2 * 3").is_err());
+        assert!(Expression::parse("1 + 2) * 3").is_err());
+        assert!(Expression::parse("1 + * 3").is_err());
+        assert!(Expression::parse("1 2").is_err());
+        assert!(Expression::parse("1 +").is_err());
+    }
+}
@@ -0,0 +1,450 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::utils::{parse_lines, parse_number, read_numbered_blocks};
+use std::collections::HashMap;
+
+type Pattern = Vec<usize>;
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Rule {
+    Character(char),
+    Sequence(Vec<Pattern>),
+}
+
+fn parse_rule(rule: &str) -> Result<(usize, Rule), Error> {
+    let mut parts = rule.split(":");
+    let rule_number = parse_number(rule, parts.next().unwrap_or(rule).trim())?;
+    let rule_part = parts
+        .next()
+        .ok_or_else(|| Error::parse(rule, rule, "missing ':' after the rule number"))?
+        .trim();
+
+    let rule = if rule_part.starts_with('"') {
+        let mut chars = rule_part.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some('"'), Some(c), Some('"'), None) => Rule::Character(c),
+            _ => {
+                return Err(Error::parse(
+                    rule,
+                    rule_part,
+                    "expected a single quoted character",
+                ))
+            }
+        }
+    } else {
+        Rule::Sequence(
+            rule_part
+                .split("|")
+                .map(|p| {
+                    p.split_whitespace()
+                        .map(|n| parse_number(rule, n))
+                        .collect::<Result<_, Error>>()
+                })
+                .collect::<Result<_, Error>>()?,
+        )
+    };
+
+    Ok((rule_number, rule))
+}
+
+fn parse_rules(rules: &str) -> Result<HashMap<usize, Rule>, Error> {
+    let parsed: HashMap<usize, Rule> = parse_lines(rules, parse_rule)?.into_iter().collect();
+
+    // every rule referenced by a sequence has to exist, otherwise matching can't finish
+    parse_lines(rules, |line| match parse_rule(line)? {
+        (_, Rule::Sequence(patterns)) => {
+            match patterns.iter().flatten().find(|n| !parsed.contains_key(n)) {
+                Some(n) => Err(Error::parse(
+                    line,
+                    line,
+                    format!("rule {} is not defined", n),
+                )),
+                None => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    })?;
+
+    Ok(parsed)
+}
+
+fn match_char(s: &str, c: char) -> bool {
+    s.starts_with(c)
+}
+
+fn match_seq(s: &str, seq: &Pattern, rules: &HashMap<usize, Rule>) -> Option<usize> {
+    let mut current = 0;
+
+    for r in seq {
+        if current >= s.len() {
+            return None;
+        }
+
+        let r = rules.get(r).unwrap();
+        let option = match_rule(&s[current..], r, rules);
+
+        if let Some(match_index) = option {
+            current += match_index
+        } else {
+            return None;
+        }
+    }
+    Some(current)
+}
+
+fn match_rule(s: &str, r: &Rule, rules: &HashMap<usize, Rule>) -> Option<usize> {
+    match r {
+        Rule::Character(c) => return if !match_char(s, *c) { None } else { Some(1) },
+        Rule::Sequence(seq) => {
+            for p in seq {
+                let m = match_seq(s, p, rules);
+
+                if m.is_some() {
+                    return m;
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn matched_full(s: &str, r: &Rule, rules: &HashMap<usize, Rule>) -> bool {
+    let maybe_matched = match_rule(s, r, rules);
+
+    if let Some(last_index) = maybe_matched {
+        last_index == s.len()
+    } else {
+        false
+    }
+}
+
+/// Whether one of the patterns of `r` matches the whole of `s`. Unlike [`matched_full`], which
+/// settles for the first pattern matching the start of `s`.
+pub(crate) fn matched_any_full(s: &str, r: &Rule, rules: &HashMap<usize, Rule>) -> bool {
+    match r {
+        Rule::Character(_) => matched_full(s, r, rules),
+        Rule::Sequence(seq) => seq.iter().any(|p| match_seq(s, p, rules) == Some(s.len())),
+    }
+}
+
+/// The looping rule 0 of part 2 unrolled into all its patterns with up to 200 repetitions of
+/// rule 42. Too slow to match against, kept to check [`match_rec`] against.
+pub(crate) fn compute_possible_rules() -> Rule {
+    // we need to match rule 42 at least 2 times followed by at least 1 31
+    let mut possible_sequences = Vec::new();
+
+    for x in 2..200 {
+        for y in 1..x {
+            let mut acc = vec![42; x];
+            acc.extend(vec![31; y]);
+            possible_sequences.push(acc);
+        }
+    }
+
+    Rule::Sequence(possible_sequences)
+}
+
+pub(crate) fn match_rec(s: &str, rules: &HashMap<usize, Rule>) -> bool {
+    let mut current = 0;
+
+    let mut count_42 = 0;
+    loop {
+        let s = &s[current..];
+
+        if let Some(match_index) = match_seq(s, &vec![42], rules) {
+            count_42 += 1;
+            current += match_index
+        } else {
+            break;
+        }
+    }
+
+    let mut count_31 = 0;
+    loop {
+        let s = &s[current..];
+
+        if let Some(match_index) = match_seq(s, &vec![31], rules) {
+            count_31 += 1;
+            current += match_index
+        } else {
+            break;
+        }
+    }
+
+    count_42 >= 2 && count_42 > count_31 && count_31 >= 1 && current == s.len()
+}
+
+/// Builds rules matching sets of words of the same length, sharing the rules of equal sets.
+struct Grammar {
+    rules: Vec<(usize, String)>,
+    free_ids: Vec<usize>,
+    known: HashMap<Vec<String>, usize>,
+    letters: [(char, usize); 2],
+}
+
+impl Grammar {
+    /// Number of a rule matching exactly `words`, which must not be empty. A new rule takes
+    /// `id` if given.
+    fn rule(&mut self, words: Vec<String>, id: Option<usize>) -> usize {
+        if id.is_none() {
+            if let Some(&known) = self.known.get(&words) {
+                return known;
+            }
+        }
+
+        let mut alternatives = Vec::new();
+        let letters = self.letters;
+        for &(letter, letter_rule) in &letters {
+            let rests: Vec<String> = words
+                .iter()
+                .filter_map(|w| w.strip_prefix(letter))
+                .map(String::from)
+                .collect();
+            if rests.is_empty() {
+                continue;
+            }
+            if rests[0].is_empty() {
+                alternatives.push(letter_rule.to_string());
+            } else {
+                alternatives.push(format!("{} {}", letter_rule, self.rule(rests, None)));
+            }
+        }
+
+        let id = id.unwrap_or_else(|| self.free_ids.pop().unwrap());
+        self.rules.push((id, alternatives.join(" | ")));
+        self.known.insert(words, id);
+        id
+    }
+}
+
+/// Rules in the shape of the puzzle's, with `size` messages: some made of words matching rules
+/// 42 and 31 in the looping pattern of the second part, the others random.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    // rules 42 and 31 split the words of WORD letters between them
+    const WORD: usize = 5;
+    let mut words: Vec<String> = (0..1 << WORD)
+        .map(|bits: usize| {
+            (0..WORD)
+                .map(|bit| if bits >> bit & 1 == 1 { 'b' } else { 'a' })
+                .collect()
+        })
+        .collect();
+    rng.shuffle(&mut words);
+    let split = rng.range(1, words.len() as u64 - 1) as usize;
+    let (words42, words31) = words.split_at(split);
+
+    let mut free_ids: Vec<usize> = (1..=255)
+        .filter(|id| ![8, 11, 31, 42].contains(id))
+        .collect();
+    rng.shuffle(&mut free_ids);
+    let letters = [
+        ('a', free_ids.pop().unwrap()),
+        ('b', free_ids.pop().unwrap()),
+    ];
+    let mut grammar = Grammar {
+        rules: vec![
+            (0, String::from("8 11")),
+            (8, String::from("42")),
+            (11, String::from("42 31")),
+        ],
+        free_ids,
+        known: HashMap::new(),
+        letters,
+    };
+    for &(letter, id) in &letters {
+        grammar.rules.push((id, format!("\"{}\"", letter)));
+    }
+    for (words, id) in [(words42, 42), (words31, 31)] {
+        let mut words = words.to_vec();
+        words.sort();
+        grammar.rule(words, Some(id));
+    }
+
+    let mut rules = grammar.rules;
+    rng.shuffle(&mut rules);
+    let mut input: String = rules
+        .iter()
+        .map(|(id, rule)| format!("{}: {}\n", id, rule))
+        .collect();
+
+    input.push('\n');
+    for _ in 0..size {
+        let message: String = if rng.chance(60) {
+            let count42 = rng.range(2, 5);
+            let count31 = rng.range(1, count42 - 1);
+            let mut message = String::new();
+            for idx in 0..count42 + count31 {
+                let words = if idx < count42 { words42 } else { words31 };
+                message.push_str(rng.choose(words).as_str());
+            }
+            message
+        } else {
+            let len = rng.range(2, 40);
+            (0..len)
+                .map(|_| if rng.chance(50) { 'a' } else { 'b' })
+                .collect()
+        };
+        input.push_str(&message);
+        input.push('\n');
+    }
+    input
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashMap<usize, Rule>, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let contents = read_numbered_blocks(input);
+        let mut blocks = contents.iter();
+
+        let (first_line, rules_block) = blocks
+            .next()
+            .ok_or_else(|| Error::end_of_input(input, "missing rules"))?;
+        let rules = parse_rules(rules_block).map_err(|e| e.offset_lines(*first_line))?;
+        if !rules.contains_key(&0) {
+            return Err(
+                Error::end_of_input(rules_block, "missing rule 0").offset_lines(*first_line)
+            );
+        }
+
+        let messages = match blocks.next() {
+            Some((_, messages)) => messages.lines().map(String::from).collect(),
+            None => Vec::new(),
+        };
+
+        Ok((rules, messages))
+    }
+
+    fn summary(&self, (rules, messages): &Self::Input) -> Summary {
+        Summary::new()
+            .fact("rules", rules.len())
+            .range("rule numbers", rules.keys())
+            .fact("messages", messages.len())
+            .range("message lengths", messages.iter().map(String::len))
+    }
+
+    fn part1(&self, (rules, messages): &Self::Input) -> Answer {
+        let rule = rules.get(&0).unwrap();
+
+        let matching_lines = messages
+            .iter()
+            .filter(|l| matched_full(l, rule, rules))
+            .count();
+
+        Answer::from(matching_lines)
+    }
+
+    fn part2(&self, (rules, messages): &Self::Input) -> Option<Answer> {
+        let mut rules = rules.clone();
+        rules.remove(&8);
+        rules.remove(&11);
+
+        let matching_lines: Vec<&String> =
+            messages.iter().filter(|l| match_rec(l, &rules)).collect();
+
+        for l in &matching_lines {
+            trace!("{:?}", l);
+        }
+
+        Some(Answer::from(matching_lines.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule_simple_rules() {
+        let rules = parse_rules("0: 1 2\r\n1: \"a\"\r\n2: 1 3 | 3 1\r\n3: \"b\"").unwrap();
+
+        assert_eq!(rules.get(&0), Some(&Rule::Sequence(vec![vec![1, 2]])));
+        assert_eq!(rules.get(&1), Some(&Rule::Character('a')));
+        assert_eq!(
+            rules.get(&2),
+            Some(&Rule::Sequence(vec![vec![1, 3], vec![3, 1]]))
+        );
+        assert_eq!(rules.get(&3), Some(&Rule::Character('b')));
+    }
+
+    #[test]
+    fn test_parse_rule_invalid_rules() {
+        assert_eq!(
+            parse_rules("0: 1 2\n1: \"a\"").unwrap_err(),
+            Error::parse("0: 1 2", "0: 1 2", "rule 2 is not defined")
+        );
+        assert!(parse_rules("0: \"ab\"").is_err());
+        assert!(parse_rules("x: 1").is_err());
+        assert!(parse_rules("0 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_rule_complex_rules() {
+        let rules = parse_rules(
+            "0: 4 1 5\r\n1: 2 3 | 3 2\r\n2: 4 4 | 5 5\r\n3: 4 5 | 5 4\r\n4: \"a\"\r\n5: \"b\"",
+        )
+        .unwrap();
+
+        assert_eq!(rules.get(&0), Some(&Rule::Sequence(vec![vec![4, 1, 5]])));
+        assert_eq!(
+            rules.get(&1),
+            Some(&Rule::Sequence(vec![vec![2, 3], vec![3, 2]]))
+        );
+        assert_eq!(
+            rules.get(&2),
+            Some(&Rule::Sequence(vec![vec![4, 4], vec![5, 5]]))
+        );
+        assert_eq!(
+            rules.get(&3),
+            Some(&Rule::Sequence(vec![vec![4, 5], vec![5, 4]]))
+        );
+        assert_eq!(rules.get(&4), Some(&Rule::Character('a')));
+        assert_eq!(rules.get(&5), Some(&Rule::Character('b')));
+    }
+
+    #[test]
+    fn test_match_char() {
+        assert!(!match_char("", 'a'));
+        assert!(match_char("a", 'a'));
+        assert!(match_char("abc", 'a'));
+        assert!(!match_char("cba", 'a'));
+    }
+
+    #[test]
+    fn test_match_seq_only_chars() {
+        let rules = parse_rules("4: \"a\"\r\n5: \"b\"").unwrap();
+
+        assert_eq!(match_seq("ab", &vec![4, 5], &rules), Some(2));
+        assert_eq!(match_seq("ba", &vec![5, 4], &rules), Some(2));
+        assert_eq!(match_seq("", &vec![5, 4], &rules), None);
+        assert_eq!(match_seq("ab", &vec![5, 4], &rules), None);
+        assert_eq!(match_seq("ba", &vec![5, 4, 4], &rules), None);
+        assert_eq!(match_seq("aba", &vec![4, 5, 4], &rules), Some(3));
+    }
+
+    #[test]
+    fn test_match_rule_complex() {
+        let rules = parse_rules("4: \"a\"\r\n5: \"b\"").unwrap();
+
+        assert_eq!(match_rule("ab", &Rule::Character('a'), &rules), Some(1));
+        assert_eq!(match_rule("ab", &Rule::Character('b'), &rules), None);
+        assert_eq!(
+            match_rule("ab", &Rule::Sequence(vec![vec![4, 4]]), &rules),
+            None
+        );
+        assert_eq!(
+            match_rule("ab", &Rule::Sequence(vec![vec![4, 5]]), &rules),
+            Some(2)
+        );
+        assert_eq!(
+            match_rule("ab", &Rule::Sequence(vec![vec![4, 4], vec![4, 5]]), &rules),
+            Some(2)
+        );
+    }
+}
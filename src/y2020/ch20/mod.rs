@@ -0,0 +1,448 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::export::{self, Color};
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_blocks, parse_number};
+use crate::{debug, trace};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+enum Pixel {
+    Black, // '#'
+    White, // '.'
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+enum BorderPosition {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl BorderPosition {
+    fn is_reverse(&self) -> bool {
+        match self {
+            BorderPosition::Top => false,
+            BorderPosition::Right => false,
+            BorderPosition::Bottom => true,
+            BorderPosition::Left => true,
+        }
+    }
+}
+
+const ALL_BORDER_POSITIONS: [BorderPosition; 4] = [
+    BorderPosition::Top,
+    BorderPosition::Right,
+    BorderPosition::Bottom,
+    BorderPosition::Left,
+];
+
+type Border = Vec<Pixel>;
+type TileId = u32;
+type BorderOrientations = HashMap<TileId, Vec<(BorderPosition, Rotation)>>;
+type MatchingBorders = HashMap<BorderPosition, Vec<(TileId, BorderPosition, Rotation)>>;
+
+#[derive(Copy, Clone, Debug)]
+enum Rotation {
+    None,
+    One,
+    Two,
+    Three,
+}
+
+const ALL_ROTATIONS: [Rotation; 4] = [
+    Rotation::None,
+    Rotation::One,
+    Rotation::Two,
+    Rotation::Three,
+];
+
+#[derive(Debug)]
+pub struct Tile {
+    id: TileId,
+    pixels: Grid<Pixel>,
+    size: usize,
+}
+
+fn parse_tile_id(line: &str) -> Result<u32, Error> {
+    let id = line
+        .strip_prefix("Tile ")
+        .and_then(|l| l.strip_suffix(":"))
+        .ok_or_else(|| Error::parse(line, line, "expected a header like 'Tile 42:'"))?;
+    parse_number(line, id)
+}
+
+impl Tile {
+    fn from_block(block: &str) -> Result<Tile, Error> {
+        let mut lines = block.lines();
+
+        let id = parse_tile_id(lines.next().unwrap_or_default())?;
+
+        let pixels = Grid::parse(&lines.collect::<Vec<_>>().join("\n"), |c| match c {
+            '.' => Some(Pixel::White),
+            '#' => Some(Pixel::Black),
+            _ => None,
+        })
+        .map_err(|e| e.offset_lines(1))?;
+
+        // only square tiles can be rotated onto each other
+        let size = pixels.width();
+        if pixels.height() != size {
+            return Err(Error::end_of_input(
+                block,
+                format!("expected {} rows of pixels", size),
+            ));
+        }
+
+        Ok(Tile { id, pixels, size })
+    }
+
+    // return the border with the given position AFTER applying the given rotation.
+    fn border(&self, position: BorderPosition, rotation: Rotation) -> Border {
+        let (border_position, reverse) = self.border_rotation(position, rotation);
+        let last = self.size - 1;
+        let mut border: Border = match border_position {
+            BorderPosition::Top => self.pixels.row(0).to_vec(),
+            BorderPosition::Right => self.pixels.column(last).cloned().collect(),
+            BorderPosition::Bottom => self.pixels.row(last).iter().rev().cloned().collect(),
+            BorderPosition::Left => self.pixels.column(0).rev().cloned().collect(),
+        };
+        if reverse {
+            border.reverse()
+        }
+        border
+    }
+
+    fn border_rotation(&self, position: BorderPosition, r: Rotation) -> (BorderPosition, bool) {
+        let num_rotations = match r {
+            Rotation::None => 0,
+            Rotation::One => 1,
+            Rotation::Two => 2,
+            Rotation::Three => 3,
+        };
+        let pos = ALL_BORDER_POSITIONS
+            .iter()
+            .position(|&x| x == position)
+            .unwrap();
+        let new_position = ALL_BORDER_POSITIONS[(pos + num_rotations) % 4];
+        let reverse = position.is_reverse() ^ new_position.is_reverse();
+
+        (new_position, reverse)
+    }
+}
+
+fn group_borders(tiles: &Vec<Tile>) -> HashMap<Border, BorderOrientations> {
+    let mut borders_by_tiles = HashMap::new();
+
+    for tile in tiles {
+        for border_position in &ALL_BORDER_POSITIONS {
+            for rotation in &ALL_ROTATIONS {
+                let border = tile.border(*border_position, *rotation);
+                let matching_tiles = borders_by_tiles.entry(border).or_insert(HashMap::new());
+                let orientations = matching_tiles.entry(tile.id).or_insert(Vec::new());
+                orientations.push((*border_position, *rotation));
+            }
+        }
+    }
+
+    borders_by_tiles
+}
+
+fn compute_matching_tiles(
+    tiles: &Vec<Tile>,
+    borders_by_tiles: &HashMap<Border, BorderOrientations>,
+) -> HashMap<TileId, MatchingBorders> {
+    let mut matching_tiles = HashMap::new();
+
+    for tile in tiles {
+        let mut m = HashMap::new();
+
+        for border_position in &ALL_BORDER_POSITIONS {
+            let border = tile.border(*border_position, Rotation::None);
+            m.insert(
+                *border_position,
+                borders_by_tiles
+                    .get(&border)
+                    .unwrap()
+                    .iter()
+                    .filter(|(&tile_id, _)| tile_id != tile.id)
+                    .flat_map(|(&t, x)| x.iter().map(move |&(p, r)| (t, p, r)))
+                    .collect(),
+            );
+        }
+
+        matching_tiles.insert(tile.id, m);
+    }
+
+    matching_tiles
+}
+
+#[allow(dead_code)]
+struct Image {
+    size: usize,
+    tiles: Vec<Vec<(TileId, Rotation)>>,
+}
+
+fn find_corners(tiles: &Vec<Tile>) -> Vec<TileId> {
+    let size = (tiles.len() as f32).sqrt() as usize;
+    debug!("trying to reconstruct an image of size {}x{}", size, size);
+
+    let borders_by_tiles = group_borders(tiles);
+
+    for v in borders_by_tiles.values() {
+        // if v.len() > 1 {
+        for (x, y) in v {
+            trace!("{} => {:?}", x, y);
+        }
+        // }
+    }
+
+    let matching_tiles = compute_matching_tiles(tiles, &borders_by_tiles);
+
+    for (tile_id, borders) in &matching_tiles {
+        trace!("For tile {}", tile_id);
+        for (border_pos, tiles) in borders {
+            trace!("\t{:?} => {:?}", border_pos, tiles);
+        }
+    }
+
+    // Tiles should have the following properties:
+    //     - if it is a corner, then at least 2 borders match other tiles
+    //     - if it is on the edge, then at least 3 borders should match other tiles
+    //     - if is is a center tile, then all four borders should match other tiles
+    //
+    // This can be used to reduce the number of possibilities in a backtracking solution.
+    matching_tiles
+        .iter()
+        .filter(|(_, borders)| borders.values().filter(|m| !m.is_empty()).count() == 2)
+        .map(|(&tile_id, _)| tile_id)
+        .collect()
+}
+
+// Checks if a tile can be put in the given position in the final image.
+// In other words, it looks if there are other tiles that can match its borders).
+#[allow(dead_code)]
+fn is_valid_for_position(
+    _tile: &Tile,
+    _rotation: Rotation,
+    image_x: usize,
+    image_y: usize,
+    image_size: usize,
+) -> bool {
+    let last = image_size - 1;
+
+    if image_x == 0 {
+        // Top border. Should match bottom border.
+    } else if image_x == last {
+        // Bottom border. Should match top border.
+    } else {
+        // Middle. Should match top and bottom borders.
+    }
+
+    if image_y == 0 {
+        // Left border. Should match right border
+    } else if image_y == last {
+        // Right border. Should match left border
+    } else {
+        // In the middle. Should match both left and right borders
+    }
+
+    true
+}
+
+fn read_tiles(input: &str) -> Result<Vec<Tile>, Error> {
+    parse_blocks(input, Tile::from_block)
+}
+
+/// Tiles of a square image cut into `size` tiles (rounded down to a square of 4 to 8836 tiles),
+/// each rotated and maybe flipped. The border shared by two tiles matches no other border, in
+/// either direction, so that the corners are the tiles with exactly two matching borders.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = ((size as f64).sqrt() as usize).clamp(2, 94);
+    let borders = 2 * side * (side + 1);
+
+    // the borders differ by their pixels between the corners, enough of them for all borders
+    let mut inner: usize = 8;
+    while ((1 << inner) - (1 << inner.div_ceil(2))) / 2 < borders {
+        inner += 1;
+    }
+    let tile_size = inner + 2;
+
+    let mut used = HashSet::new();
+    let mut unique_border = |rng: &mut Rng| loop {
+        let pixels = rng.range(0, (1 << inner) - 1);
+        let reversed = pixels.reverse_bits() >> (64 - inner);
+        if pixels != reversed && used.insert(pixels) {
+            used.insert(reversed);
+            return pixels;
+        }
+    };
+
+    // corners are shared by the 4 tiles around them, borders by the 2 tiles on either side
+    let corners = Grid::from_fn(side + 1, side + 1, |_, _| rng.chance(50));
+    let horizontal = Grid::from_fn(side, side + 1, |_, _| unique_border(rng));
+    let vertical = Grid::from_fn(side + 1, side, |_, _| unique_border(rng));
+
+    let ids = rng.distinct(1000, 9999, side * side);
+    let last = tile_size - 1;
+    let mut tiles = Vec::new();
+    for (idx, id) in ids.into_iter().enumerate() {
+        let (x, y) = (idx % side, idx / side);
+        let bit = |pixels: u64, at: usize| pixels >> (at - 1) & 1 == 1;
+
+        let mut tile = Grid::from_fn(tile_size, tile_size, |px, py| {
+            match (px == 0 || px == last, py == 0 || py == last) {
+                (true, true) => corners[(x + px / last, y + py / last)],
+                (false, true) => bit(horizontal[(x, y + py / last)], px),
+                (true, false) => bit(vertical[(x + px / last, y)], py),
+                (false, false) => rng.chance(50),
+            }
+        });
+        for _ in 0..rng.range(0, 3) {
+            tile = tile.rotate_clockwise();
+        }
+        if rng.chance(50) {
+            tile = tile.flip_horizontal();
+        }
+
+        tiles.push(format!(
+            "Tile {}:\n{}",
+            id,
+            tile.render(|&black| if black { '#' } else { '.' })
+        ));
+    }
+    tiles.join("\n")
+}
+
+/// The tiles in the order of the input, laid out in a square, the corners found in part 1 in
+/// red. Both parts give the same frame.
+pub(crate) fn frames(input: &str, _part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    let tiles = read_tiles(input)?;
+    let corners = find_corners(&tiles);
+
+    let rendered: Vec<Grid<Color>> = tiles
+        .iter()
+        .map(|tile| {
+            let corner = corners.contains(&tile.id);
+            tile.pixels.map(|pixel| match (pixel, corner) {
+                (Pixel::Black, false) => Color(20, 60, 120),
+                (Pixel::Black, true) => Color(200, 40, 40),
+                (Pixel::White, _) => Color(230, 240, 255),
+            })
+        })
+        .collect();
+    let columns = (rendered.len() as f64).sqrt().ceil() as usize;
+
+    Ok(vec![export::tile(&rendered, columns, Color::WHITE)])
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Tile>, Error> {
+        read_tiles(input)
+    }
+
+    fn summary(&self, tiles: &Vec<Tile>) -> Summary {
+        Summary::new()
+            .fact("tiles", tiles.len())
+            .range("tile sizes", tiles.iter().map(|t| t.size))
+            .range("tile ids", tiles.iter().map(|t| t.id))
+    }
+
+    fn part1(&self, tiles: &Vec<Tile>) -> Answer {
+        debug!("Read {} tiles", tiles.len());
+
+        let corners = find_corners(tiles);
+
+        Answer::from(corners.iter().map(|&id| u64::from(id)).product::<u64>())
+    }
+
+    fn part2(&self, _tiles: &Vec<Tile>) -> Option<Answer> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tile_from_block() {
+        /*
+               #.#
+               ..#
+               ##.
+        */
+        let tile = Tile::from_block("Tile 5:\r\n#.#\r\n..#\r\n##.").unwrap();
+
+        assert_eq!(tile.size, 3);
+        assert_eq!(tile.id, 5);
+        assert_eq!(
+            tile.pixels,
+            Grid::from_rows(vec![
+                vec![Pixel::Black, Pixel::White, Pixel::Black],
+                vec![Pixel::White, Pixel::White, Pixel::Black],
+                vec![Pixel::Black, Pixel::Black, Pixel::White]
+            ])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_read_tiles_line_endings() {
+        let input = "Tile 5:\n#.#\n..#\n##.\n\nTile 7:\n...\n.#.\n...\n";
+
+        for input in [input.to_string(), input.replace('\n', "\r\n")].iter() {
+            let tiles = read_tiles(input).unwrap();
+
+            assert_eq!(tiles.len(), 2);
+            assert_eq!(tiles[0].id, 5);
+            assert_eq!(tiles[1].id, 7);
+            assert_eq!(tiles[1].pixels[(1, 1)], Pixel::Black);
+        }
+    }
+
+    #[test]
+    fn test_tile_from_block_invalid() {
+        assert!(Tile::from_block("Tile x:\n#.\n.#").is_err());
+        assert!(Tile::from_block("Tile 5:\n#.\n.").is_err());
+        assert!(Tile::from_block("Tile 5:\n#.\n.#\n..").is_err());
+        assert_eq!(
+            read_tiles("Tile 5:\n#.\n.#\n\nTile 7:\n#.\n.o").unwrap_err(),
+            Error::parse(".o", "o", "invalid character").offset_lines(6)
+        );
+    }
+
+    // #[test]
+    // fn test_tile_borders() {
+    //     /*
+    //            #.#
+    //            ..#
+    //            ##.
+    //     */
+    //     let tile = Tile::from_block("Tile 5:\r\n#.#\r\n..#\r\n##.");
+    //
+    //     assert_eq!(
+    //         tile.border(BorderPosition::Top),
+    //         vec![Pixel::Black, Pixel::White, Pixel::Black]
+    //     );
+    //     assert_eq!(
+    //         tile.border(BorderPosition::Right),
+    //         vec![Pixel::Black, Pixel::Black, Pixel::White]
+    //     );
+    //     assert_eq!(
+    //         tile.border(BorderPosition::Bottom),
+    //         vec![Pixel::White, Pixel::Black, Pixel::Black]
+    //     );
+    //     assert_eq!(
+    //         tile.border(BorderPosition::Left),
+    //         vec![Pixel::Black, Pixel::White, Pixel::Black]
+    //     );
+    // }
+}
@@ -0,0 +1,236 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::parse::{self, Cursor};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::utils::parse_lines;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+type Allergen = String;
+type Ingredient = String;
+
+fn parse_ingredient_list(l: &str) -> Result<(HashSet<Ingredient>, HashSet<Allergen>), Error> {
+    parse::line(l, |cursor| {
+        let ingredients = cursor.separated(" ", Cursor::word)?;
+        let allergens = cursor.delimited(" (contains ", ")", |cursor| {
+            cursor.separated(", ", Cursor::word)
+        })?;
+
+        Ok((
+            ingredients.into_iter().map(String::from).collect(),
+            allergens.into_iter().map(String::from).collect(),
+        ))
+    })
+}
+
+type FoodList = Vec<(HashSet<Ingredient>, HashSet<Allergen>)>;
+
+fn read_ingredient_lists(input: &str) -> Result<FoodList, Error> {
+    parse_lines(input, parse_ingredient_list)
+}
+
+fn intersection<'a>(sets: &[&'a HashSet<Ingredient>]) -> HashSet<&'a Ingredient> {
+    let mut res = HashSet::new();
+
+    if sets.is_empty() {
+        return res;
+    }
+
+    let first_set = sets[0];
+    let other_sets = &sets[1..];
+
+    for ingr in first_set {
+        if other_sets.iter().all(|s| s.contains(ingr)) {
+            res.insert(ingr);
+        }
+    }
+
+    res
+}
+
+fn find_allergen_possibilities(food_list: &FoodList) -> HashMap<Allergen, HashSet<&Ingredient>> {
+    let mut allergen_foods: HashMap<Allergen, Vec<&HashSet<Ingredient>>> = HashMap::new();
+    for (ingr_list, allergen_list) in food_list {
+        for allergen in allergen_list {
+            let e = allergen_foods.entry(allergen.clone()).or_default();
+            e.push(ingr_list);
+        }
+    }
+
+    for x in &allergen_foods {
+        trace!("{:?}", x);
+    }
+
+    let allergen_possibilities: HashMap<Allergen, HashSet<&Ingredient>> = allergen_foods
+        .iter()
+        .map(|(il, al)| (il.clone(), intersection(al)))
+        .collect();
+
+    for x in &allergen_possibilities {
+        trace!("{:?}", x);
+    }
+
+    allergen_possibilities
+}
+
+fn count_safe_ingredients(food_list: &FoodList) -> usize {
+    let allergen_possibilities = find_allergen_possibilities(food_list);
+
+    let mut x: HashSet<&Ingredient> = HashSet::new();
+    for ingr_list in allergen_possibilities.values() {
+        for ingr in ingr_list {
+            x.insert(ingr);
+        }
+    }
+
+    let mut cnt = 0;
+    for (ingr_list, _) in food_list {
+        for ingr in ingr_list {
+            if !x.contains(ingr) {
+                cnt += 1;
+            }
+        }
+    }
+
+    cnt
+}
+
+fn canonical_dangerous_ingredients(food_list: &FoodList) -> String {
+    let mut allergen_possibilities = find_allergen_possibilities(food_list);
+
+    let mut queue: VecDeque<Allergen> = VecDeque::new();
+    let mut ingredient_allergens: Vec<(Ingredient, Allergen)> = Vec::new();
+
+    for (allergen, ingr_set) in &allergen_possibilities {
+        if ingr_set.len() == 1 {
+            queue.push_back(allergen.clone());
+        }
+    }
+
+    while let Some(allergen) = queue.pop_front() {
+        let (al, il) = allergen_possibilities.remove_entry(&allergen).unwrap();
+        let ingr: String = String::from(*il.iter().next().unwrap());
+
+        for (allergen, ingr_set) in allergen_possibilities.iter_mut() {
+            if ingr_set.contains(&ingr) {
+                ingr_set.remove(&ingr);
+
+                if ingr_set.len() == 1 {
+                    queue.push_back(allergen.clone());
+                }
+            }
+        }
+
+        ingredient_allergens.push((ingr, al));
+    }
+
+    trace!("{:?}", &ingredient_allergens);
+
+    ingredient_allergens.sort_by(|(_, xa), (_, ya)| xa.cmp(ya));
+
+    ingredient_allergens
+        .iter()
+        .map(|(i, _)| i.as_str())
+        .collect::<Vec<&str>>()
+        .join(",")
+}
+
+/// A list of `size` foods (at least 1) with 8 allergens, each found in exactly one ingredient.
+/// Two foods per allergen are added at the end, which share no safe ingredient, so that each
+/// allergen can only be in its ingredient.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    const ALLERGENS: [&str; 8] = [
+        "dairy",
+        "eggs",
+        "fish",
+        "nuts",
+        "peanuts",
+        "sesame",
+        "shellfish",
+        "soy",
+    ];
+    const SAFE: usize = 60;
+
+    let mut names = HashSet::new();
+    let mut ingredients = Vec::new();
+    while ingredients.len() < ALLERGENS.len() + SAFE {
+        let name = rng.word(3, 8);
+        if names.insert(name.clone()) {
+            ingredients.push(name);
+        }
+    }
+    let (dangerous, safe) = ingredients.split_at(ALLERGENS.len());
+
+    // (allergens listed, dangerous ingredients, safe ingredients) of each food
+    let mut foods: Vec<(Vec<usize>, Vec<usize>, Vec<usize>)> = Vec::new();
+    for _ in 0..size.max(1) {
+        let mut listed: Vec<usize> = (0..ALLERGENS.len()).filter(|_| rng.chance(20)).collect();
+        listed.truncate(3);
+        if listed.is_empty() {
+            listed.push(rng.index(ALLERGENS.len()));
+        }
+        let contained = (0..ALLERGENS.len())
+            .filter(|a| listed.contains(a) || rng.chance(30))
+            .collect();
+        let mut safe: Vec<usize> = (0..SAFE).collect();
+        rng.shuffle(&mut safe);
+        safe.truncate(rng.range(2, 12) as usize);
+        foods.push((listed, contained, safe));
+    }
+    for allergen in 0..ALLERGENS.len() {
+        let mut safe: Vec<usize> = (0..SAFE).collect();
+        rng.shuffle(&mut safe);
+        let (first, second) = safe.split_at(SAFE / 2);
+        for safe in [first, second] {
+            let safe = safe[..rng.range(2, 12) as usize].to_vec();
+            foods.push((vec![allergen], vec![allergen], safe));
+        }
+    }
+
+    let mut lines = Vec::new();
+    for (listed, contained, safe_contained) in foods {
+        let mut food: Vec<&str> = contained.iter().map(|&a| dangerous[a].as_str()).collect();
+        food.extend(safe_contained.iter().map(|&s| safe[s].as_str()));
+        rng.shuffle(&mut food);
+
+        let listed: Vec<&str> = listed.iter().map(|&a| ALLERGENS[a]).collect();
+        lines.push(format!(
+            "{} (contains {})",
+            food.join(" "),
+            listed.join(", ")
+        ));
+    }
+    lines.join("\n")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = FoodList;
+
+    fn parse(&self, input: &str) -> Result<FoodList, Error> {
+        read_ingredient_lists(input)
+    }
+
+    fn summary(&self, food_list: &FoodList) -> Summary {
+        let ingredients: HashSet<&Ingredient> = food_list.iter().flat_map(|(i, _)| i).collect();
+        let allergens: HashSet<&Allergen> = food_list.iter().flat_map(|(_, a)| a).collect();
+        Summary::new()
+            .fact("foods", food_list.len())
+            .fact("ingredients", ingredients.len())
+            .fact("allergens", allergens.len())
+            .range(
+                "ingredients per food",
+                food_list.iter().map(|(i, _)| i.len()),
+            )
+    }
+
+    fn part1(&self, food_list: &FoodList) -> Answer {
+        Answer::from(count_safe_ingredients(food_list))
+    }
+
+    fn part2(&self, food_list: &FoodList) -> Option<Answer> {
+        Some(Answer::from(canonical_dangerous_ingredients(food_list)))
+    }
+}
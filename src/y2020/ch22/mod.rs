@@ -0,0 +1,215 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_blocks, parse_lines, parse_number};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, Clone)]
+pub struct Player {
+    id: usize,
+    deck: VecDeque<usize>,
+}
+
+fn parse_player_id(s: &str) -> Result<usize, Error> {
+    let id = s
+        .strip_prefix("Player ")
+        .and_then(|s| s.strip_suffix(":"))
+        .ok_or_else(|| Error::parse(s, s, "expected a header like 'Player 1:'"))?;
+    parse_number(s, id)
+}
+
+impl Player {
+    fn from_block(s: &str) -> Result<Player, Error> {
+        let mut it = s.lines();
+
+        let id = parse_player_id(it.next().unwrap_or_default())?;
+        let deck: VecDeque<usize> =
+            parse_lines(&it.collect::<Vec<_>>().join("\n"), |l| parse_number(l, l))
+                .map_err(|e| e.offset_lines(1))?
+                .into_iter()
+                .collect();
+
+        Ok(Player { id, deck })
+    }
+
+    fn play<'a>(&'a mut self, other: &'a mut Player) -> &'a Player {
+        while !self.deck.is_empty() && !other.deck.is_empty() {
+            let my_first = self.deck.pop_front().unwrap();
+            let other_first = other.deck.pop_front().unwrap();
+
+            if my_first > other_first {
+                self.deck.push_back(my_first);
+                self.deck.push_back(other_first);
+            } else {
+                other.deck.push_back(other_first);
+                other.deck.push_back(my_first);
+            }
+        }
+
+        if self.deck.is_empty() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn play_rec<'a>(&'a mut self, other: &'a mut Player) -> &'a Player {
+        let mut played_decks: HashSet<(VecDeque<usize>, VecDeque<usize>)> = HashSet::new();
+
+        while !self.deck.is_empty() && !other.deck.is_empty() {
+            let game_state = (self.deck.clone(), other.deck.clone());
+
+            if played_decks.contains(&game_state) {
+                return self;
+            } else {
+                played_decks.insert(game_state);
+            }
+
+            let my_first = self.deck.pop_front().unwrap();
+            let other_first = other.deck.pop_front().unwrap();
+
+            if self.deck.len() >= my_first && other.deck.len() >= other_first {
+                let mut self_copy = self.copy_player(my_first);
+                let mut other_copy = other.copy_player(other_first);
+
+                let winner = self_copy.play_rec(&mut other_copy);
+
+                if winner.id == self.id {
+                    self.deck.push_back(my_first);
+                    self.deck.push_back(other_first);
+                } else {
+                    other.deck.push_back(other_first);
+                    other.deck.push_back(my_first);
+                }
+            } else {
+                if my_first > other_first {
+                    self.deck.push_back(my_first);
+                    self.deck.push_back(other_first);
+                } else {
+                    other.deck.push_back(other_first);
+                    other.deck.push_back(my_first);
+                }
+            }
+        }
+
+        if self.deck.is_empty() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn score(&self) -> usize {
+        self.deck
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(idx, el)| el * (idx + 1))
+            .sum()
+    }
+
+    fn copy_player(&self, cards: usize) -> Player {
+        let deck_copy = self
+            .deck
+            .iter()
+            .take(cards)
+            .cloned()
+            .collect::<VecDeque<usize>>();
+        Player {
+            id: self.id,
+            deck: deck_copy,
+        }
+    }
+}
+
+fn read_players(input: &str) -> Result<(Player, Player), Error> {
+    let mut players = parse_blocks(input, Player::from_block)?.into_iter();
+
+    match (players.next(), players.next(), players.next()) {
+        (Some(player1), Some(player2), None) => Ok((player1, player2)),
+        _ => Err(Error::end_of_input(input, "expected exactly two players")),
+    }
+}
+
+/// Two decks of `size` cards each (at least 1), dealt from the cards 1 to `2 * size`.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut cards: Vec<usize> = (1..=2 * size).collect();
+    rng.shuffle(&mut cards);
+
+    cards
+        .chunks(size)
+        .enumerate()
+        .map(|(idx, deck)| {
+            let deck: Vec<String> = deck.iter().map(|card| card.to_string()).collect();
+            format!("Player {}:\n{}\n", idx + 1, deck.join("\n"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Player, Player);
+
+    fn parse(&self, input: &str) -> Result<(Player, Player), Error> {
+        read_players(input)
+    }
+
+    fn summary(&self, (player1, player2): &(Player, Player)) -> Summary {
+        Summary::new()
+            .fact("cards of player 1", player1.deck.len())
+            .fact("cards of player 2", player2.deck.len())
+            .range("card values", player1.deck.iter().chain(&player2.deck))
+    }
+
+    fn part1(&self, players: &(Player, Player)) -> Answer {
+        let (mut player1, mut player2) = players.clone();
+
+        let winner = player1.play(&mut player2);
+
+        Answer::from(winner.score())
+    }
+
+    fn part2(&self, players: &(Player, Player)) -> Option<Answer> {
+        let (mut player1, mut player2) = players.clone();
+
+        let winner = player1.play_rec(&mut player2);
+
+        Some(Answer::from(winner.score()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10
+";
+
+    #[test]
+    fn test_read_players_line_endings() {
+        for input in [EXAMPLE.to_string(), EXAMPLE.replace('\n', "\r\n")].iter() {
+            let (player1, player2) = read_players(input).unwrap();
+
+            assert_eq!(player1.id, 1);
+            assert_eq!(player1.deck, vec![9, 2, 6, 3, 1]);
+            assert_eq!(player2.id, 2);
+            assert_eq!(player2.deck, vec![5, 8, 4, 7, 10]);
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use crate::answers::input_hash;
+use crate::check::Summary;
+use crate::checkpoint::{self, Checkpointer, Decoder, Encoder, Simulation, Snapshot};
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::progress::{self, Progress, Silent};
+use crate::solution::{Answer, Solution};
+use crate::{debug, trace, warn};
+use std::convert::TryFrom;
+
+/// The cups of part 1, in a vector rotated and spliced at each move. Too slow for part 2, kept
+/// to check [`Deck`] against.
+pub(crate) struct Game {
+    labels: Vec<u32>,
+    current: u32,
+    max_value: u32,
+}
+
+impl Game {
+    pub(crate) fn new(s: &str) -> Game {
+        let labels = s
+            .chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .collect::<Vec<u32>>();
+
+        Game {
+            current: labels[0],
+            max_value: *labels.iter().max().unwrap(),
+            labels,
+        }
+    }
+
+    fn remove_next3(&mut self, current_pos: usize) -> Vec<u32> {
+        let mut result = Vec::new();
+
+        for _ in 0..3 {
+            if current_pos + 1 < self.labels.len() {
+                result.push(self.labels.remove(current_pos + 1));
+            } else {
+                result.push(self.labels.remove(0));
+            }
+        }
+
+        result
+    }
+
+    fn insert(&mut self, values: Vec<u32>) {
+        let mut target = self.current - 1;
+
+        target = loop {
+            if target == 0 {
+                target = self.max_value
+            } else if values.contains(&target) {
+                target -= 1;
+            } else {
+                break target;
+            }
+        };
+        trace!("destination: {}", target);
+
+        let pos = self.find(target) + 1;
+        for (idx, value) in values.into_iter().enumerate() {
+            self.labels.insert(pos + idx, value);
+        }
+    }
+
+    fn find(&self, el: u32) -> usize {
+        self.labels.iter().position(|x| *x == el).unwrap()
+    }
+
+    fn position(&self, p: usize) -> usize {
+        p % self.labels.len()
+    }
+
+    pub(crate) fn iterate(&mut self) {
+        trace!("cups: {:?}", self.labels);
+        trace!("current: {}", self.current);
+
+        let current_pos = self.find(self.current);
+        let next = self.remove_next3(current_pos);
+
+        trace!("pick up: {:?}", &next);
+
+        self.insert(next);
+        let current_pos = self.find(self.current);
+        self.current = self.labels[self.position(current_pos + 1)];
+    }
+
+    /// Labels of the cups after cup 1, going around the circle.
+    pub(crate) fn value(&self) -> String {
+        let pos = self.find(1);
+        let mut res = String::new();
+
+        for idx in pos + 1..self.labels.len() {
+            res.push_str(&self.labels[idx].to_string());
+        }
+
+        for idx in 0..pos {
+            res.push_str(&self.labels[idx].to_string());
+        }
+
+        res
+    }
+}
+
+/// The cups of part 2, as a linked list over the labels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deck {
+    initial_cards: String,
+    // cards[x] = y => after card x comes card y
+    cards: Vec<usize>,
+    current: usize,
+    rounds: usize,
+}
+
+impl Deck {
+    /// `capacity` cups, the first ones labeled as in `initial_cards`.
+    pub fn new(initial_cards: &str, capacity: usize) -> Deck {
+        let actual_capacity = capacity + 1;
+
+        let initial_cards_order = initial_cards
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as usize)
+            .collect::<Vec<usize>>();
+        let first_card = initial_cards_order[0];
+        let last_card = initial_cards_order[initial_cards_order.len() - 1];
+
+        let mut cards: Vec<usize> = vec![0; actual_capacity];
+
+        // modify initial order
+        for pair in initial_cards_order.windows(2) {
+            cards[pair[0]] = pair[1];
+        }
+
+        // add the rest of the cards
+        let max_value = *initial_cards_order.iter().max().unwrap();
+        for (el, card) in cards
+            .iter_mut()
+            .enumerate()
+            .take(actual_capacity - 1)
+            .skip(max_value + 1)
+        {
+            *card = el + 1;
+        }
+
+        // link last to the first
+        if capacity == initial_cards.len() {
+            cards[last_card] = first_card;
+        } else {
+            cards[last_card] = max_value + 1;
+            cards[actual_capacity - 1] = first_card;
+        }
+
+        Deck {
+            initial_cards: String::from(initial_cards),
+            cards,
+            current: first_card,
+            rounds: 0,
+        }
+    }
+
+    fn iterate(&mut self) {
+        let next_first = self.cards[self.current];
+        let next_second = self.cards[next_first];
+        let next_third = self.cards[next_second];
+        let after = self.cards[next_third];
+
+        let mut target = self.current - 1;
+        target = loop {
+            if target == 0 {
+                target = self.cards.len() - 1;
+            } else if target == next_first || target == next_second || target == next_third {
+                target -= 1;
+            } else {
+                break target;
+            }
+        };
+        let target_after = self.cards[target];
+
+        self.cards[self.current] = after;
+        self.cards[next_third] = target_after;
+        self.cards[target] = next_first;
+
+        self.current = self.cards[self.current];
+        self.rounds += 1;
+    }
+
+    pub fn play(&mut self, rounds: usize) {
+        self.play_reporting(rounds, &mut Silent);
+    }
+
+    /// Like [`Deck::play`], reporting the number of rounds played to `progress`.
+    pub fn play_reporting(&mut self, rounds: usize, progress: &mut dyn Progress) {
+        let stride = progress::stride(rounds);
+        let mut played = 0;
+        while played < rounds {
+            let next = rounds.min(played + stride);
+            for _ in played..next {
+                self.iterate();
+            }
+            played = next;
+            progress.update(played, Some(rounds));
+        }
+        progress.finish();
+    }
+
+    /// The cup right after cup `card`.
+    pub fn after(&self, card: usize) -> usize {
+        self.cards[card]
+    }
+
+    /// Labels of the cups after cup 1, going around the circle.
+    pub fn value(&self) -> String {
+        let mut value = String::new();
+        let mut card = self.after(1);
+        while card != 1 {
+            value.push_str(&card.to_string());
+            card = self.after(card);
+        }
+        value
+    }
+}
+
+impl Snapshot for Deck {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.values(self.initial_cards.bytes().map(u64::from));
+        encoder.u64(self.current as u64);
+        encoder.u64(self.rounds as u64);
+        encoder.values(self.cards.iter().map(|&card| card as u64));
+    }
+
+    fn decode(decoder: &mut Decoder) -> Option<Deck> {
+        let initial_cards = decoder
+            .values()?
+            .into_iter()
+            .map(|c| u8::try_from(c).ok().map(char::from))
+            .collect::<Option<String>>()?;
+        let current = decoder.u64()? as usize;
+        let rounds = decoder.u64()? as usize;
+        let cards: Vec<usize> = decoder.values()?.into_iter().map(|c| c as usize).collect();
+        if current >= cards.len() || cards.iter().any(|&card| card >= cards.len()) {
+            return None;
+        }
+
+        Some(Deck {
+            initial_cards,
+            cards,
+            current,
+            rounds,
+        })
+    }
+}
+
+impl Simulation for Deck {
+    fn position(&self) -> usize {
+        self.rounds
+    }
+
+    fn advance_to(&mut self, position: usize) {
+        self.play(position.saturating_sub(self.rounds));
+    }
+
+    fn continues(&self, start: &Deck) -> bool {
+        self.initial_cards == start.initial_cards && self.cards.len() == start.cards.len()
+    }
+}
+
+/// Plays `rounds` rounds with `capacity` cups, saving the deck with `checkpointer` and resuming
+/// from its last save.
+pub fn play_resumable(
+    initial_cards: &str,
+    capacity: usize,
+    rounds: usize,
+    checkpointer: &Checkpointer,
+    progress: &mut dyn Progress,
+) -> Result<Deck, Error> {
+    let name = format!("ch23-{}-{:016x}", capacity, input_hash(initial_cards));
+    checkpointer.run(&name, Deck::new(initial_cards, capacity), rounds, progress)
+}
+
+/// The labels of `size` cups, clamped to 5 to 9 cups so that each label is a single digit.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut labels: Vec<char> = ('1'..='9').take(size.clamp(5, 9)).collect();
+    rng.shuffle(&mut labels);
+    labels.into_iter().collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, Error> {
+        let labels = input.trim();
+
+        // the cups have to be labeled 1..=n, each label used once
+        for (idx, c) in labels.char_indices() {
+            let label = &labels[idx..idx + c.len_utf8()];
+            match c.to_digit(10) {
+                Some(d) if d >= 1 && d as usize <= labels.len() => {}
+                _ => return Err(Error::parse(labels, label, "invalid cup label")),
+            }
+            if labels[..idx].contains(c) {
+                return Err(Error::parse(labels, label, "duplicate cup label"));
+            }
+        }
+        if labels.len() < 5 {
+            return Err(Error::parse(labels, labels, "expected at least 5 cups"));
+        }
+
+        Ok(String::from(labels))
+    }
+
+    fn summary(&self, labels: &String) -> Summary {
+        Summary::new()
+            .fact("cups", labels.len())
+            .fact("labels", labels)
+    }
+
+    fn part1(&self, labels: &String) -> Answer {
+        let mut game = Game::new(labels);
+
+        for it in 0..100 {
+            trace!("move {}", it + 1);
+            game.iterate();
+        }
+
+        debug!("final: {:?}", game.labels);
+        Answer::from(game.value())
+    }
+
+    fn part2(&self, labels: &String) -> Option<Answer> {
+        let (capacity, rounds) = (1000000, 10000000);
+        let mut progress = progress::reporter("day 23");
+
+        let deck = checkpoint::configured()
+            .and_then(|checkpointer| {
+                match play_resumable(labels, capacity, rounds, &checkpointer, progress.as_mut()) {
+                    Ok(deck) => Some(deck),
+                    Err(e) => {
+                        warn!("{}, playing without checkpoints", e);
+                        None
+                    }
+                }
+            })
+            .unwrap_or_else(|| {
+                let mut deck = Deck::new(labels, capacity);
+                deck.play_reporting(rounds, progress.as_mut());
+                deck
+            });
+
+        let first = deck.after(1);
+        debug!("cups after 1: {} and {}", first, deck.after(first));
+        Some(Answer::from(first * deck.after(first)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deck_resumes() {
+        let checkpointer = Checkpointer::new(
+            std::env::temp_dir().join(format!("code-advent-2020-ch23-{}", std::process::id())),
+            4,
+        );
+
+        let mut expected = Deck::new("389125467", 20);
+        expected.play(10);
+
+        assert_eq!(
+            play_resumable("389125467", 20, 6, &checkpointer, &mut Silent).unwrap(),
+            {
+                let mut deck = Deck::new("389125467", 20);
+                deck.play(6);
+                deck
+            }
+        );
+        assert_eq!(
+            play_resumable("389125467", 20, 10, &checkpointer, &mut Silent).unwrap(),
+            expected
+        );
+        assert_eq!(
+            checkpointer.load::<Deck>(&format!("ch23-20-{:016x}", input_hash("389125467"))),
+            Ok(Some(expected))
+        );
+
+        std::fs::remove_dir_all(checkpointer.directory()).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+use crate::automaton::{Automaton, HexGrid, Rule};
+use crate::check::Summary;
+use crate::debug;
+use crate::error::Error;
+use crate::export::Color;
+use crate::generate::Rng;
+use crate::grid::{Grid, HexDirection, HexTile};
+use crate::progress::{self, Progress};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse_lines;
+use std::collections::HashSet;
+
+// e se ne w nw sw
+/// Parses directions written without delimiters, like `esenee`.
+pub fn parse_directions(directions: &str) -> Result<Vec<HexDirection>, Error> {
+    let mut result = Vec::new();
+
+    let mut it = directions.char_indices().peekable();
+    while let Some((idx, c)) = it.next() {
+        let direction = match c {
+            'e' => HexDirection::East,
+            'w' => HexDirection::West,
+            's' | 'n' => {
+                // read next char
+                let next = it.next().map(|(_, next)| next);
+                match (c, next) {
+                    ('s', Some('w')) => HexDirection::SouthWest,
+                    ('s', Some('e')) => HexDirection::SouthEast,
+                    ('n', Some('w')) => HexDirection::NorthWest,
+                    ('n', Some('e')) => HexDirection::NorthEast,
+                    _ => {
+                        let end = it.peek().map_or(directions.len(), |&(end, _)| end);
+                        return Err(Error::parse(
+                            directions,
+                            &directions[idx..end],
+                            "invalid direction",
+                        ));
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::parse(
+                    directions,
+                    &directions[idx..idx + c.len_utf8()],
+                    "invalid direction",
+                ))
+            }
+        };
+        result.push(direction);
+    }
+
+    Ok(result)
+}
+
+fn read_tiles(input: &str) -> Result<Vec<HexTile>, Error> {
+    parse_lines(input, |l| Ok(HexTile::walk(&parse_directions(l)?)))
+}
+
+/// Tiles flipped an odd number of times end up black.
+fn initial_black_tiles(tiles: &[HexTile]) -> HashSet<HexTile> {
+    let mut black_tiles = HashSet::new();
+    for tile in tiles {
+        if !black_tiles.remove(tile) {
+            black_tiles.insert(*tile);
+        }
+    }
+    black_tiles
+}
+
+/// Days the floor changes in part 2.
+const DAYS: usize = 100;
+
+/// Black tiles stay black with 1 or 2 black neighbors, white ones flip with exactly 2.
+fn floor_rule() -> Rule {
+    Rule::new(&[2], &[1, 2])
+}
+
+/// Number of black tiles after `days` days, reporting the days done to `progress`.
+pub fn simulate(tiles: &[HexTile], days: usize, progress: &mut dyn Progress) -> usize {
+    let black_tiles = initial_black_tiles(tiles);
+
+    let mut floor = Automaton::sparse(HexGrid, floor_rule(), black_tiles);
+    floor.run(days, |floor| {
+        progress.update(floor.generation(), Some(days))
+    });
+    progress.finish();
+
+    debug!("{} black tiles after {} days", floor.count_alive(), days);
+    floor.count_alive()
+}
+
+/// `size` lines (at least 1) of 10 to 25 random steps each.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    const STEPS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+
+    (0..size.max(1))
+        .map(|_| {
+            let len = rng.range(10, 25);
+            (0..len).map(|_| *rng.choose(&STEPS)).collect::<String>() + "\n"
+        })
+        .collect()
+}
+
+/// The floor after the flips of part 1, or after each day of part 2 as well. A tile is drawn as
+/// two cells side by side, each row of tiles shifted by one cell from the rows around it.
+pub(crate) fn frames(input: &str, part: u8) -> Result<Vec<Grid<Color>>, Error> {
+    let tiles = read_tiles(input)?;
+    let mut floors = vec![initial_black_tiles(&tiles)];
+    if part != 1 {
+        let mut floor = Automaton::sparse(HexGrid, floor_rule(), floors[0].clone());
+        floor.run(DAYS, |floor| {
+            floors.push(floor.live_cells().into_iter().collect())
+        });
+    }
+
+    // all the frames have the bounds of the largest floor
+    let bounds = |coordinate: fn(&HexTile) -> i32| {
+        let values = floors.iter().flatten().map(coordinate);
+        (values.clone().min().unwrap_or(0), values.max().unwrap_or(0))
+    };
+    let ((min_x, max_x), (min_y, max_y)) = (bounds(HexTile::x), bounds(HexTile::y));
+    let (width, height) = ((max_x - min_x + 2) as usize, (max_y - min_y + 1) as usize);
+
+    Ok(floors
+        .iter()
+        .map(|black_tiles| {
+            Grid::from_fn(width, height, |x, y| {
+                // north is up
+                let (x, y) = (x as i32 + min_x, max_y - y as i32);
+                let x = if (x + y).rem_euclid(2) == 0 { x } else { x - 1 };
+                if black_tiles.contains(&HexTile::new(x, y)) {
+                    Color(30, 30, 30)
+                } else {
+                    Color(250, 250, 250)
+                }
+            })
+        })
+        .collect())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<HexTile>;
+
+    fn parse(&self, input: &str) -> Result<Vec<HexTile>, Error> {
+        read_tiles(input)
+    }
+
+    fn summary(&self, tiles: &Vec<HexTile>) -> Summary {
+        let distinct: HashSet<&HexTile> = tiles.iter().collect();
+        Summary::new()
+            .fact("paths", tiles.len())
+            .fact("tiles reached", distinct.len())
+    }
+
+    fn part1(&self, tiles: &Vec<HexTile>) -> Answer {
+        Answer::from(initial_black_tiles(tiles).len())
+    }
+
+    fn part2(&self, tiles: &Vec<HexTile>) -> Option<Answer> {
+        let mut progress = progress::reporter("day 24");
+        Some(Answer::from(simulate(tiles, DAYS, progress.as_mut())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_directions() {
+        assert_eq!(
+            parse_directions("nwwswee").unwrap(),
+            vec![
+                HexDirection::NorthWest,
+                HexDirection::West,
+                HexDirection::SouthWest,
+                HexDirection::East,
+                HexDirection::East
+            ]
+        );
+        assert_eq!(
+            parse_directions("esew").unwrap(),
+            vec![
+                HexDirection::East,
+                HexDirection::SouthEast,
+                HexDirection::West
+            ],
+        )
+    }
+
+    #[test]
+    fn test_parse_directions_invalid() {
+        assert_eq!(
+            parse_directions("esxw").unwrap_err(),
+            Error::parse("esxw", "sx", "invalid direction")
+        );
+        assert_eq!(
+            parse_directions("ewn").unwrap_err(),
+            Error::parse("ewn", "n", "invalid direction")
+        );
+        assert_eq!(
+            parse_directions("eqw").unwrap_err(),
+            Error::parse("eqw", "q", "invalid direction")
+        );
+    }
+
+    #[test]
+    fn test_simulate_reports_days() {
+        let mut days = Vec::new();
+        let mut progress = progress::Callback(|day, total| days.push((day, total)));
+
+        // a lone black tile has no black neighbors and flips back
+        assert_eq!(simulate(&[HexTile::new(0, 0)], 2, &mut progress), 0);
+        assert_eq!(simulate(&[], 0, &mut progress), 0);
+        assert_eq!(days, vec![(1, Some(2)), (2, Some(2))]);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::check::Summary;
+use crate::error::Error;
+use crate::generate::Rng;
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_lines, parse_number};
+
+const MOD: u64 = 20201227;
+
+fn transform(subject: u64, loop_size: u64) -> u64 {
+    let mut res = 1;
+    for _ in 0..loop_size {
+        res *= subject;
+        res %= MOD;
+    }
+    res
+}
+
+fn guess_loop_size(public_key: u64) -> u64 {
+    let mut loop_size = 0;
+    let mut current = 1;
+
+    while current != public_key {
+        current *= 7;
+        current %= MOD;
+        loop_size += 1;
+    }
+
+    loop_size
+}
+
+/// The public keys of a door and a card whose loop sizes are at most `size` (at least 1).
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    let max = size.max(1) as u64;
+    let door = transform(7, rng.range(1, max));
+    let card = transform(7, rng.range(1, max));
+    format!("{}\n{}\n", door, card)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (u64, u64);
+
+    fn parse(&self, input: &str) -> Result<(u64, u64), Error> {
+        let keys = parse_lines(input, |l| parse_number(l, l))?;
+
+        match keys[..] {
+            [door_public_key, card_public_key] => Ok((door_public_key, card_public_key)),
+            _ => Err(Error::end_of_input(
+                input,
+                "expected exactly two public keys",
+            )),
+        }
+    }
+
+    fn summary(&self, &(door_public_key, card_public_key): &(u64, u64)) -> Summary {
+        Summary::new()
+            .fact("door public key", door_public_key)
+            .fact("card public key", card_public_key)
+    }
+
+    fn part1(&self, &(door_public_key, card_public_key): &(u64, u64)) -> Answer {
+        let door_loop_size = guess_loop_size(door_public_key);
+        // let card_loop_size = guess_loop_size(card_public_key);
+
+        let door_encryption_key = transform(card_public_key, door_loop_size);
+        // let card_encryption_key = transform(door_public_key, card_loop_size);
+
+        Answer::from(door_encryption_key)
+    }
+
+    fn part2(&self, _keys: &(u64, u64)) -> Option<Answer> {
+        None
+    }
+}
@@ -0,0 +1,90 @@
+//! Advent of Code 2020, one module per day.
+
+use crate::solution::DynSolution;
+
+pub mod ch01;
+pub mod ch02;
+pub mod ch03;
+pub mod ch04;
+pub mod ch05;
+pub mod ch06;
+pub mod ch07;
+pub mod ch08;
+pub mod ch09;
+pub mod ch10;
+pub mod ch11;
+pub mod ch12;
+pub mod ch13;
+pub mod ch14;
+pub mod ch15;
+pub mod ch16;
+pub mod ch17;
+pub mod ch18;
+pub mod ch19;
+pub mod ch20;
+pub mod ch21;
+pub mod ch22;
+pub mod ch23;
+pub mod ch24;
+pub mod ch25;
+
+pub const YEAR: u16 = 2020;
+
+pub static SOLUTIONS: [&dyn DynSolution; 25] = [
+    &ch01::Day,
+    &ch02::Day,
+    &ch03::Day,
+    &ch04::Day,
+    &ch05::Day,
+    &ch06::Day,
+    &ch07::Day,
+    &ch08::Day,
+    &ch09::Day {
+        preamble: ch09::PREAMBLE,
+    },
+    &ch10::Day,
+    &ch11::Day,
+    &ch12::Day,
+    &ch13::Day,
+    &ch14::Day,
+    &ch15::Day,
+    &ch16::Day,
+    &ch17::Day,
+    &ch18::Day,
+    &ch19::Day,
+    &ch20::Day,
+    &ch21::Day,
+    &ch22::Day,
+    &ch23::Day,
+    &ch24::Day,
+    &ch25::Day,
+];
+
+/// Worked examples of each day, see [`crate::fixtures`].
+pub static EXAMPLES: [&str; 25] = [
+    include_str!("ch01/examples.txt"),
+    include_str!("ch02/examples.txt"),
+    include_str!("ch03/examples.txt"),
+    include_str!("ch04/examples.txt"),
+    include_str!("ch05/examples.txt"),
+    include_str!("ch06/examples.txt"),
+    include_str!("ch07/examples.txt"),
+    include_str!("ch08/examples.txt"),
+    include_str!("ch09/examples.txt"),
+    include_str!("ch10/examples.txt"),
+    include_str!("ch11/examples.txt"),
+    include_str!("ch12/examples.txt"),
+    include_str!("ch13/examples.txt"),
+    include_str!("ch14/examples.txt"),
+    include_str!("ch15/examples.txt"),
+    include_str!("ch16/examples.txt"),
+    include_str!("ch17/examples.txt"),
+    include_str!("ch18/examples.txt"),
+    include_str!("ch19/examples.txt"),
+    include_str!("ch20/examples.txt"),
+    include_str!("ch21/examples.txt"),
+    include_str!("ch22/examples.txt"),
+    include_str!("ch23/examples.txt"),
+    include_str!("ch24/examples.txt"),
+    include_str!("ch25/examples.txt"),
+];